@@ -0,0 +1,59 @@
+//! Fraction arithmetic and comparisons for typical operand sizes.
+//!
+//! Run `cargo bench --bench fraction_ops -- --save-baseline before` on the old revision and
+//! `cargo bench --bench fraction_ops -- --baseline before` on the new one to compare them.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use uniswap_sdk_core::{fraction, prelude::*};
+
+/// Named pairs of operands covering the common magnitudes
+fn cases() -> [(&'static str, Fraction, Fraction); 4] {
+    [
+        // percentages and fee tiers
+        ("percent", fraction!(3 / 1000), fraction!(1 / 200)),
+        // 18 decimal token amounts sharing a scale
+        (
+            "amount",
+            fraction!(1_234_567_890_123_456_789 / 1_000_000_000_000_000_000),
+            fraction!(987_654_321_987_654_321 / 1_000_000_000_000_000_000),
+        ),
+        // prices between tokens with different decimals
+        (
+            "price",
+            fraction!(1_000_000_000_000_000_000 / 3_456_789_012),
+            fraction!(2_500_000_000_000_000_000 / 1_999_999_999),
+        ),
+        // Q64.96 sqrt prices
+        (
+            "q96",
+            fraction!(79_228_162_514_264_337_593_543_950_336 / 1_000_000_007),
+            fraction!(56_022_770_974_786_139_918_731_938_227 / 998_244_353),
+        ),
+    ]
+}
+
+fn fraction_ops(c: &mut Criterion) {
+    for (name, a, b) in cases() {
+        let mut group = c.benchmark_group(name);
+        group.bench_function("add", |bench| {
+            bench.iter(|| black_box(a.clone()) + black_box(&b))
+        });
+        group.bench_function("sub", |bench| {
+            bench.iter(|| black_box(a.clone()) - black_box(&b))
+        });
+        group.bench_function("mul", |bench| {
+            bench.iter(|| black_box(a.clone()) * black_box(&b))
+        });
+        group.bench_function("div", |bench| {
+            bench.iter(|| black_box(a.clone()) / black_box(&b))
+        });
+        group.bench_function("eq", |bench| bench.iter(|| black_box(&a) == black_box(&b)));
+        group.bench_function("cmp", |bench| {
+            bench.iter(|| black_box(&a).cmp(black_box(&b)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, fraction_ops);
+criterion_main!(benches);
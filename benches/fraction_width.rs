@@ -0,0 +1,46 @@
+//! Compares fraction arithmetic backed by `I256` against the default 512-bit `BigInt`.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use fastnum::I256;
+use uniswap_sdk_core::{fraction, prelude::*};
+
+type Narrow = FractionLike<(), I256>;
+
+fn operands() -> (Fraction, Fraction) {
+    // sqrt price style values that stay well within 256 bits
+    (
+        fraction!(79_228_162_514_264_337_593_543_950_336 / 1_000_000_007),
+        fraction!(56_022_770_974_786_139_918_731_938_227 / 998_244_353),
+    )
+}
+
+fn bench_width<I: FractionInt>(
+    c: &mut Criterion,
+    name: &str,
+    a: &FractionLike<(), I>,
+    b: &FractionLike<(), I>,
+) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("add", |bench| {
+        bench.iter(|| black_box(a.clone()) + black_box(b))
+    });
+    group.bench_function("mul", |bench| {
+        bench.iter(|| black_box(a.clone()) * black_box(b))
+    });
+    group.bench_function("div", |bench| {
+        bench.iter(|| black_box(a.clone()) / black_box(b))
+    });
+    group.bench_function("cmp", |bench| bench.iter(|| black_box(a) < black_box(b)));
+    group.finish();
+}
+
+fn fraction_width(c: &mut Criterion) {
+    let (a, b) = operands();
+    bench_width(c, "BigInt", &a, &b);
+    let a: Narrow = a.try_cast().unwrap();
+    let b: Narrow = b.try_cast().unwrap();
+    bench_width(c, "I256", &a, &b);
+}
+
+criterion_group!(benches, fraction_width);
+criterion_main!(benches);
@@ -48,6 +48,58 @@ pub enum ChainId {
     INK = 57073,
 }
 
+impl TryFrom<u64> for ChainId {
+    type Error = Error;
+
+    /// Looks up the [`ChainId`] variant with the given numeric chain ID.
+    ///
+    /// Returns [`Error::UnsupportedChain`] if no variant has this ID.
+    #[inline]
+    fn try_from(chain_id: u64) -> Result<Self, Error> {
+        match chain_id {
+            x if x == Self::MAINNET as u64 => Ok(Self::MAINNET),
+            x if x == Self::GOERLI as u64 => Ok(Self::GOERLI),
+            x if x == Self::SEPOLIA as u64 => Ok(Self::SEPOLIA),
+            x if x == Self::OPTIMISM as u64 => Ok(Self::OPTIMISM),
+            x if x == Self::OPTIMISM_GOERLI as u64 => Ok(Self::OPTIMISM_GOERLI),
+            x if x == Self::OPTIMISM_SEPOLIA as u64 => Ok(Self::OPTIMISM_SEPOLIA),
+            x if x == Self::ARBITRUM_ONE as u64 => Ok(Self::ARBITRUM_ONE),
+            x if x == Self::ARBITRUM_GOERLI as u64 => Ok(Self::ARBITRUM_GOERLI),
+            x if x == Self::ARBITRUM_SEPOLIA as u64 => Ok(Self::ARBITRUM_SEPOLIA),
+            x if x == Self::POLYGON as u64 => Ok(Self::POLYGON),
+            x if x == Self::POLYGON_MUMBAI as u64 => Ok(Self::POLYGON_MUMBAI),
+            x if x == Self::CELO as u64 => Ok(Self::CELO),
+            x if x == Self::CELO_ALFAJORES as u64 => Ok(Self::CELO_ALFAJORES),
+            x if x == Self::GNOSIS as u64 => Ok(Self::GNOSIS),
+            x if x == Self::MOONBEAM as u64 => Ok(Self::MOONBEAM),
+            x if x == Self::BNB as u64 => Ok(Self::BNB),
+            x if x == Self::AVALANCHE as u64 => Ok(Self::AVALANCHE),
+            x if x == Self::BASE_GOERLI as u64 => Ok(Self::BASE_GOERLI),
+            x if x == Self::BASE_SEPOLIA as u64 => Ok(Self::BASE_SEPOLIA),
+            x if x == Self::BASE as u64 => Ok(Self::BASE),
+            x if x == Self::ZORA as u64 => Ok(Self::ZORA),
+            x if x == Self::ZORA_SEPOLIA as u64 => Ok(Self::ZORA_SEPOLIA),
+            x if x == Self::ROOTSTOCK as u64 => Ok(Self::ROOTSTOCK),
+            x if x == Self::BLAST as u64 => Ok(Self::BLAST),
+            x if x == Self::ZKSYNC as u64 => Ok(Self::ZKSYNC),
+            x if x == Self::WORLDCHAIN as u64 => Ok(Self::WORLDCHAIN),
+            x if x == Self::UNICHAIN_SEPOLIA as u64 => Ok(Self::UNICHAIN_SEPOLIA),
+            x if x == Self::UNICHAIN as u64 => Ok(Self::UNICHAIN),
+            x if x == Self::MONAD_TESTNET as u64 => Ok(Self::MONAD_TESTNET),
+            x if x == Self::SONEIUM as u64 => Ok(Self::SONEIUM),
+            x if x == Self::MONAD as u64 => Ok(Self::MONAD),
+            x if x == Self::XLAYER as u64 => Ok(Self::XLAYER),
+            x if x == Self::LINEA as u64 => Ok(Self::LINEA),
+            x if x == Self::TEMPO as u64 => Ok(Self::TEMPO),
+            x if x == Self::MEGAETH as u64 => Ok(Self::MEGAETH),
+            x if x == Self::ARC as u64 => Ok(Self::ARC),
+            x if x == Self::ROBINHOOD as u64 => Ok(Self::ROBINHOOD),
+            x if x == Self::INK as u64 => Ok(Self::INK),
+            _ => Err(Error::UnsupportedChain(chain_id)),
+        }
+    }
+}
+
 lazy_static! {
     /// Average block time in seconds for chains with known deployment metadata.
     pub static ref AVERAGE_BLOCK_TIMES_SECONDS: HashMap<u64, f64> = HashMap::from_iter([
@@ -154,6 +206,17 @@ mod tests {
         assert_eq!(get_average_block_time_secs(57073), Ok(1.0));
     }
 
+    #[test]
+    fn converts_numeric_chain_ids() {
+        assert_eq!(ChainId::try_from(1), Ok(ChainId::MAINNET));
+        assert_eq!(ChainId::try_from(100), Ok(ChainId::GNOSIS));
+        assert_eq!(ChainId::try_from(999999999), Ok(ChainId::ZORA_SEPOLIA));
+        for chain in SUPPORTED_CHAINS {
+            assert_eq!(ChainId::try_from(chain as u64), Ok(chain));
+        }
+        assert_eq!(ChainId::try_from(0), Err(Error::UnsupportedChain(0)));
+    }
+
     #[test]
     fn rejects_unregistered_chain() {
         assert_eq!(
@@ -0,0 +1,540 @@
+use crate::prelude::*;
+
+/// Length in bytes of an encoded [`Address`]
+const ADDRESS_BYTES: usize = 20;
+
+/// Types that can be written in the compact binary format described in the [module
+/// docs](self).
+pub trait Encode {
+    /// Appends the encoding of `self` to `out`
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Returns the encoding of `self`
+    #[inline]
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out);
+        out
+    }
+}
+
+/// Types that can be read back from the compact binary format described in the [module
+/// docs](self).
+///
+/// The context `C` provides what the encoding leaves out, e.g. a [`TokenResolver`] for values
+/// that reference their currencies by `(chain_id, address)`.
+pub trait Decode<C: ?Sized = ()>: Sized {
+    /// Decodes a value from the start of `input` and advances `input` past it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is truncated or malformed, or if the decoded value violates
+    /// an invariant of the type.
+    fn decode(input: &mut &[u8], context: &C) -> Result<Self, Error>;
+
+    /// Decodes a value that must span all of `bytes`
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Decode::decode`] and [`Error::TrailingBytes`] if bytes are left
+    /// over.
+    #[inline]
+    fn from_bytes(mut bytes: &[u8], context: &C) -> Result<Self, Error> {
+        let value = Self::decode(&mut bytes, context)?;
+        if !bytes.is_empty() {
+            return Err(Error::TrailingBytes(bytes.len()));
+        }
+        Ok(value)
+    }
+}
+
+/// Looks up the [`Token`] referenced by a chain ID and address
+pub trait TokenResolver {
+    /// Returns the token deployed at `address` on `chain_id`, if known
+    fn resolve(&self, chain_id: u64, address: Address) -> Option<Token>;
+}
+
+impl<F: Fn(u64, Address) -> Option<Token>> TokenResolver for F {
+    #[inline]
+    fn resolve(&self, chain_id: u64, address: Address) -> Option<Token> {
+        self(chain_id, address)
+    }
+}
+
+impl TokenResolver for [Token] {
+    #[inline]
+    fn resolve(&self, chain_id: u64, address: Address) -> Option<Token> {
+        self.iter()
+            .find(|token| token.chain_id == chain_id && token.address == address)
+            .cloned()
+    }
+}
+
+/// Splits the first `len` bytes off `input`
+#[inline]
+const fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    if input.len() < len {
+        return Err(Error::UnexpectedEof);
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+/// Writes `value` as an unsigned LEB128 varint
+#[inline]
+fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads a canonical unsigned LEB128 varint
+#[inline]
+fn decode_varint(input: &mut &[u8]) -> Result<u64, Error> {
+    let mut value = 0_u64;
+    for shift in (0..64).step_by(7) {
+        let byte = take(input, 1)?[0];
+        let bits = (byte & 0x7f) as u64;
+        if bits << shift >> shift != bits {
+            return Err(Error::InvalidVarint);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            // a trailing zero byte would make the encoding ambiguous
+            if byte == 0 && shift != 0 {
+                return Err(Error::InvalidVarint);
+            }
+            return Ok(value);
+        }
+    }
+    Err(Error::InvalidVarint)
+}
+
+/// Reads a varint used as a length
+#[inline]
+fn decode_len(input: &mut &[u8]) -> Result<usize, Error> {
+    usize::try_from(decode_varint(input)?).map_err(|_| Error::InvalidVarint)
+}
+
+/// Writes a [`BigInt`] as a varint header `len << 1 | sign` followed by the `len` big-endian
+/// bytes of its magnitude without leading zeros
+#[inline]
+fn encode_big_int(value: &BigInt, out: &mut Vec<u8>) {
+    let bytes: Vec<u8> = value
+        .unsigned_abs()
+        .digits()
+        .iter()
+        .rev()
+        .flat_map(|digit| digit.to_be_bytes())
+        .skip_while(|byte| *byte == 0)
+        .collect();
+    encode_varint(
+        ((bytes.len() as u64) << 1) | value.is_negative() as u64,
+        out,
+    );
+    out.extend_from_slice(&bytes);
+}
+
+/// Reads a [`BigInt`] written by [`encode_big_int`]
+#[inline]
+fn decode_big_int(input: &mut &[u8]) -> Result<BigInt, Error> {
+    let header = decode_varint(input)?;
+    let negative = header & 1 == 1;
+    let len = usize::try_from(header >> 1).map_err(|_| Error::InvalidVarint)?;
+    let bytes = take(input, len)?;
+    // canonical encodings have no leading zeros and no negative zero
+    if bytes.first() == Some(&0) || (negative && bytes.is_empty()) {
+        return Err(Error::NonCanonical);
+    }
+    let magnitude = BigUint::from_be_slice(bytes).ok_or(Error::Overflow(BigInt::BITS))?;
+    let min_magnitude = BigInt::MIN.unsigned_abs();
+    if magnitude > min_magnitude || (!negative && magnitude == min_magnitude) {
+        return Err(Error::Overflow(BigInt::BITS));
+    }
+    // the magnitude of `BigInt::MIN` wraps to itself
+    let value = BigInt::from_bits(magnitude);
+    Ok(if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    })
+}
+
+/// Writes an optional string as a varint of its length plus one, zero for `None`, followed by
+/// its UTF-8 bytes
+#[inline]
+fn encode_optional_str(value: Option<&str>, out: &mut Vec<u8>) {
+    match value {
+        None => out.push(0),
+        Some(s) => {
+            encode_varint(s.len() as u64 + 1, out);
+            out.extend_from_slice(s.as_bytes());
+        }
+    }
+}
+
+/// Reads an optional string written by [`encode_optional_str`]
+#[inline]
+fn decode_optional_str(input: &mut &[u8]) -> Result<Option<String>, Error> {
+    match decode_len(input)? {
+        0 => Ok(None),
+        len => {
+            let bytes = take(input, len - 1)?;
+            let s = core::str::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)?;
+            Ok(Some(s.into()))
+        }
+    }
+}
+
+/// Writes a currency reference as its chain ID varint followed by its 20 address bytes
+#[inline]
+fn encode_currency_ref(token: &Token, out: &mut Vec<u8>) {
+    encode_varint(token.chain_id, out);
+    out.extend_from_slice(token.address.as_slice());
+}
+
+/// Reads the chain ID and address of a [`Token`]
+#[inline]
+fn decode_token_key(input: &mut &[u8]) -> Result<(u64, Address), Error> {
+    let chain_id = decode_varint(input)?;
+    if chain_id == 0 {
+        return Err(Error::ZeroChainId);
+    }
+    let address = Address::from_slice(take(input, ADDRESS_BYTES)?);
+    Ok((chain_id, address))
+}
+
+/// Reads a currency reference and resolves it to a [`Token`]
+#[inline]
+fn decode_currency_ref<R: TokenResolver + ?Sized>(
+    input: &mut &[u8],
+    resolver: &R,
+) -> Result<Token, Error> {
+    let (chain_id, address) = decode_token_key(input)?;
+    match resolver.resolve(chain_id, address) {
+        Some(token) if token.chain_id == chain_id && token.address == address => Ok(token),
+        _ => Err(Error::UnknownCurrency { chain_id, address }),
+    }
+}
+
+/// Reads a numerator and a non-zero denominator
+#[inline]
+fn decode_parts(input: &mut &[u8]) -> Result<(BigInt, BigInt), Error> {
+    let numerator = decode_big_int(input)?;
+    let denominator = decode_big_int(input)?;
+    if denominator.is_zero() {
+        return Err(Error::ZeroDenominator);
+    }
+    Ok((numerator, denominator))
+}
+
+impl Encode for Token {
+    #[inline]
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_currency_ref(self, out);
+        out.push(self.decimals);
+        encode_optional_str(self.symbol.as_deref(), out);
+        encode_optional_str(self.name.as_deref(), out);
+        encode_varint(self.buy_fee_bps, out);
+        encode_varint(self.sell_fee_bps, out);
+    }
+}
+
+impl Decode for Token {
+    #[inline]
+    fn decode(input: &mut &[u8], _: &()) -> Result<Self, Error> {
+        let (chain_id, address) = decode_token_key(input)?;
+        let decimals = take(input, 1)?[0];
+        let symbol = decode_optional_str(input)?;
+        let name = decode_optional_str(input)?;
+        let buy_fee_bps = decode_varint(input)?;
+        let sell_fee_bps = decode_varint(input)?;
+        Ok(Self::new(
+            chain_id,
+            address,
+            decimals,
+            symbol,
+            name,
+            buy_fee_bps,
+            sell_fee_bps,
+        ))
+    }
+}
+
+impl<M: Clone> Encode for FractionLike<M> {
+    /// Writes the numerator and denominator. The metadata is not encoded.
+    #[inline]
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_big_int(&self.numerator, out);
+        encode_big_int(&self.denominator, out);
+    }
+}
+
+impl Decode for Fraction {
+    #[inline]
+    fn decode(input: &mut &[u8], _: &()) -> Result<Self, Error> {
+        let (numerator, denominator) = decode_parts(input)?;
+        Self::try_new(numerator, denominator)
+    }
+}
+
+impl Decode for Percent {
+    #[inline]
+    fn decode(input: &mut &[u8], _: &()) -> Result<Self, Error> {
+        let (numerator, denominator) = decode_parts(input)?;
+        FractionBase::try_new(numerator, denominator, IsPercent)
+    }
+}
+
+impl CurrencyAmount<Token> {
+    /// Writes the currency reference, numerator and denominator of the amount
+    #[inline]
+    pub fn encode_with_currency(&self, out: &mut Vec<u8>) {
+        encode_currency_ref(&self.meta.currency, out);
+        self.encode(out);
+    }
+}
+
+impl<R: TokenResolver + ?Sized> Decode<R> for CurrencyAmount<Token> {
+    /// Reads an amount written by [`CurrencyAmount::encode_with_currency`], resolving its
+    /// currency with `resolver`.
+    ///
+    /// Returns [`Error::UintOverflow`] if the amount exceeds `MAX_UINT256` and
+    /// [`Error::Overflow`] if the sign of the fraction cannot be normalized.
+    #[inline]
+    fn decode(input: &mut &[u8], resolver: &R) -> Result<Self, Error> {
+        let currency = decode_currency_ref(input, resolver)?;
+        let (numerator, denominator) = decode_parts(input)?;
+        Self::from_fractional_amount(currency, numerator, denominator)
+    }
+}
+
+impl Price<Token, Token> {
+    /// Writes the base and quote currency references followed by the numerator and denominator
+    /// of the price
+    #[inline]
+    pub fn encode_with_currencies(&self, out: &mut Vec<u8>) {
+        encode_currency_ref(&self.meta.base_currency, out);
+        encode_currency_ref(&self.meta.quote_currency, out);
+        self.encode(out);
+    }
+}
+
+impl<R: TokenResolver + ?Sized> Decode<R> for Price<Token, Token> {
+    /// Reads a price written by [`Price::encode_with_currencies`], resolving its currencies with
+    /// `resolver`.
+    ///
+    /// Returns [`Error::ZeroDenominator`] if the denominator is zero and [`Error::Overflow`] if the
+    /// sign of the fraction cannot be normalized.
+    #[inline]
+    fn decode(input: &mut &[u8], resolver: &R) -> Result<Self, Error> {
+        let base_currency = decode_currency_ref(input, resolver)?;
+        let quote_currency = decode_currency_ref(input, resolver)?;
+        let (numerator, denominator) = decode_parts(input)?;
+        Self::try_new(base_currency, quote_currency, denominator, numerator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+
+    fn tokens() -> [Token; 2] {
+        [
+            token!(1, "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", 18, "WETH"),
+            token!(
+                1,
+                "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                6,
+                "USDC",
+                "USD Coin"
+            ),
+        ]
+    }
+
+    fn varint(value: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_varint(value, &mut out);
+        out
+    }
+
+    fn big_int(value: BigInt) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_big_int(&value, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_varint() {
+        assert_eq!(varint(0), [0x00]);
+        assert_eq!(varint(127), [0x7f]);
+        assert_eq!(varint(300), [0xac, 0x02]);
+        for value in [0, 1, 127, 128, 42161, u64::MAX] {
+            let bytes = varint(value);
+            assert_eq!(decode_varint(&mut bytes.as_slice()), Ok(value));
+        }
+        assert_eq!(varint(u64::MAX).len(), 10);
+        let invalid: [&[u8]; 4] = [
+            &[0x80],
+            &[0x80, 0x00],
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02],
+            &[0xff; 11],
+        ];
+        for bytes in invalid {
+            assert!(decode_varint(&mut &bytes[..]).is_err(), "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn test_big_int() {
+        assert_eq!(big_int(BigInt::ZERO), [0x00]);
+        assert_eq!(big_int(BigInt::from(255)), [0x02, 0xff]);
+        assert_eq!(big_int(BigInt::from(-256)), [0x05, 0x01, 0x00]);
+        for value in [
+            BigInt::ONE,
+            -BigInt::ONE,
+            MAX_UINT256,
+            BigInt::MAX,
+            BigInt::MIN,
+        ] {
+            let bytes = big_int(value);
+            assert_eq!(decode_big_int(&mut bytes.as_slice()), Ok(value));
+        }
+        assert_eq!(big_int(MAX_UINT256).len(), 33);
+    }
+
+    #[test]
+    fn test_big_int_rejects_invalid() {
+        // leading zero and negative zero
+        assert!(decode_big_int(&mut &[0x02, 0x00][..]).is_err());
+        assert!(decode_big_int(&mut &[0x01][..]).is_err());
+        // 2^511 only fits as a negative value
+        let magnitude = |header: u64| {
+            let mut bytes = varint(header);
+            bytes.push(0x80);
+            bytes.extend_from_slice(&[0; 63]);
+            bytes
+        };
+        assert_eq!(
+            decode_big_int(&mut magnitude(128).as_slice()),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+        assert_eq!(
+            decode_big_int(&mut magnitude(129).as_slice()),
+            Ok(BigInt::MIN)
+        );
+        // truncated
+        assert_eq!(
+            decode_big_int(&mut &[0x04, 0x01][..]),
+            Err(Error::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_token() {
+        let [weth, usdc] = tokens();
+        let taxed = Token::new(10, usdc.address, 6, None, None, 100, 250);
+        for token in [weth, usdc, taxed] {
+            assert_eq!(Token::from_bytes(&token.to_bytes(), &()), Ok(token));
+        }
+        let mut bytes = tokens()[0].to_bytes();
+        bytes.push(0);
+        assert_eq!(Token::from_bytes(&bytes, &()), Err(Error::TrailingBytes(1)));
+        bytes[0] = 0;
+        assert_eq!(Token::from_bytes(&bytes, &()), Err(Error::ZeroChainId));
+    }
+
+    #[test]
+    fn test_fraction() {
+        let fraction = Fraction::new(-7, 3);
+        assert_eq!(fraction.to_bytes(), [0x03, 0x07, 0x02, 0x03]);
+        assert_eq!(
+            Fraction::from_bytes(&fraction.to_bytes(), &()),
+            Ok(fraction)
+        );
+        let percent = Percent::new(3, 1000);
+        assert_eq!(Percent::from_bytes(&percent.to_bytes(), &()), Ok(percent));
+        assert_eq!(
+            Fraction::from_bytes(&[0x02, 0x01, 0x00], &()),
+            Err(Error::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_currency_amount() {
+        let tokens = tokens();
+        let amount = CurrencyAmount::from_fractional_amount(tokens[1].clone(), 10, 3).unwrap();
+        let mut bytes = Vec::new();
+        amount.encode_with_currency(&mut bytes);
+        // chain ID, address and two single byte integers
+        assert_eq!(bytes.len(), 1 + ADDRESS_BYTES + 4);
+        assert_eq!(
+            CurrencyAmount::from_bytes(&bytes, tokens.as_slice()),
+            Ok(amount)
+        );
+        let unknown = |_, _| None;
+        assert_eq!(
+            CurrencyAmount::<Token>::from_bytes(&bytes, &unknown),
+            Err(Error::UnknownCurrency {
+                chain_id: 1,
+                address: tokens[1].address,
+            })
+        );
+
+        let mut bytes = Vec::new();
+        encode_currency_ref(&tokens[1], &mut bytes);
+        encode_big_int(&(MAX_UINT256 + BigInt::ONE), &mut bytes);
+        encode_big_int(&BigInt::ONE, &mut bytes);
+        assert_eq!(
+            CurrencyAmount::from_bytes(&bytes, tokens.as_slice()),
+            Err(Error::uint_overflow(Fraction::new(
+                MAX_UINT256 + BigInt::ONE,
+                1
+            )))
+        );
+        for (numerator, denominator) in [(BigInt::ONE, BigInt::MIN), (BigInt::MIN, -BigInt::ONE)] {
+            let mut bytes = Vec::new();
+            encode_currency_ref(&tokens[1], &mut bytes);
+            encode_big_int(&numerator, &mut bytes);
+            encode_big_int(&denominator, &mut bytes);
+            assert_eq!(
+                CurrencyAmount::from_bytes(&bytes, tokens.as_slice()),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+        }
+    }
+
+    #[test]
+    fn test_price() {
+        let [weth, usdc] = tokens();
+        let price = Price::new(weth, usdc, BigInt::from(10).pow(18), 4_000_000_000_i64);
+        let mut bytes = Vec::new();
+        price.encode_with_currencies(&mut bytes);
+        let resolver = |chain_id, address| tokens().as_slice().resolve(chain_id, address);
+        let decoded = Price::from_bytes(&bytes, &resolver).unwrap();
+        assert_eq!(decoded, price);
+        assert_eq!(decoded.meta.scalar, price.meta.scalar);
+
+        let truncated = &bytes[..bytes.len() - 1];
+        assert_eq!(
+            Price::from_bytes(truncated, &resolver),
+            Err(Error::UnexpectedEof)
+        );
+
+        for (numerator, denominator) in [(BigInt::ONE, BigInt::MIN), (BigInt::MIN, -BigInt::ONE)] {
+            let mut bytes = Vec::new();
+            encode_currency_ref(&tokens()[0], &mut bytes);
+            encode_currency_ref(&tokens()[1], &mut bytes);
+            encode_big_int(&numerator, &mut bytes);
+            encode_big_int(&denominator, &mut bytes);
+            assert_eq!(
+                Price::from_bytes(&bytes, &resolver),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+        }
+    }
+}
@@ -3,6 +3,11 @@ use alloy_primitives::U256;
 
 /// Represents the various types of trades.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub enum TradeType {
     /// Indicates that the trade is based on an exact input amount.
     ExactInput,
@@ -11,20 +16,69 @@ pub enum TradeType {
     ExactOutput,
 }
 
-/// Represents three various ways to round
+/// Represents the various ways to round
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
 pub enum Rounding {
-    /// Rounds down to the nearest whole number.
+    /// Rounds towards zero, e.g. `2.7 → 2` and `-2.7 → -2`.
     RoundDown,
 
     /// Rounds to the nearest whole number, rounding halfway cases away from zero.
     #[default]
     RoundHalfUp,
 
-    /// Rounds up to the nearest whole number.
+    /// Rounds away from zero, e.g. `2.1 → 3` and `-2.1 → -3`.
     RoundUp,
+
+    /// Rounds to the nearest whole number, rounding halfway cases towards zero.
+    RoundHalfDown,
+
+    /// Rounds to the nearest whole number, rounding halfway cases to the nearest even number,
+    /// a.k.a. banker's rounding.
+    RoundHalfEven,
+
+    /// Rounds towards positive infinity, e.g. `2.1 → 3` and `-2.7 → -2`.
+    RoundCeiling,
+
+    /// Rounds towards negative infinity, e.g. `2.7 → 2` and `-2.1 → -3`.
+    RoundFloor,
 }
 
+/// Determines how parsing handles input with more fraction digits than a currency has decimals
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum PrecisionMode {
+    /// Rejects the input with [`Error::ExcessPrecision`].
+    #[default]
+    Exact,
+
+    /// Rounds the input to the decimals of the currency.
+    Round(Rounding),
+}
+
+/// Determines how exact decimal rendering handles values whose decimal expansion does not
+/// terminate, e.g. `1/3`
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum NonTerminating {
+    /// Fails with [`Error::NonTerminating`].
+    Reject,
+
+    /// Renders the repeating cycle in parentheses, e.g. `0.(3)`.
+    Repeating {
+        /// The maximum number of fraction digits including the cycle
+        max_digits: usize,
+    },
+
+    /// Rounds to a fixed number of fraction digits.
+    Round {
+        /// The number of fraction digits
+        decimal_places: usize,
+        /// The rounding strategy
+        rounding: Rounding,
+    },
+}
+
+/// The number of basis points in 100%, the denominator of token transfer fees
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
 /// Represents the maximum amount contained in a uint256
 pub const MAX_UINT256: BigInt =
     BigInt::from_bits(BigUint::from_le_slice(&U256::MAX.to_le_bytes::<32>()).unwrap());
@@ -4,17 +4,25 @@ use alloy_primitives::ChainId;
 /// A currency is any fungible financial instrument, including Ether, all ERC20 tokens, and other
 /// chain-native currencies
 pub trait BaseCurrency: BaseCurrencyCore + Clone {
-    /// Returns the address of the currency.
+    /// Returns the address of the currency, i.e. of its wrapped token if it is native
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoWrappedToken`] if the currency is native and has no wrapped token.
     #[inline]
-    fn address(&self) -> Address {
-        self.wrapped().address
+    fn address(&self) -> Result<Address, Error> {
+        self.wrapped().map(|token| token.address)
     }
 
     /// Returns whether this currency is functionally equivalent to the other currency
     fn equals(&self, other: &impl BaseCurrency) -> bool;
 
     /// Returns a Token that represents the wrapped equivalent of the native currency
-    fn wrapped(&self) -> &Token;
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoWrappedToken`] if the currency is native and has no wrapped token.
+    fn wrapped(&self) -> Result<&Token, Error>;
 }
 
 pub trait BaseCurrencyCore {
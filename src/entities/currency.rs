@@ -79,7 +79,13 @@ macro_rules! impl_base_currency {
                     }
                 }
 
-                match_currency_method!(wrapped, &Token);
+                #[inline]
+                fn wrapped(&self) -> Result<&Token, Error> {
+                    match self {
+                        Currency::NativeCurrency(ether) => ether.wrapped(),
+                        Currency::Token(token) => token.wrapped(),
+                    }
+                }
             }
         )*
     };
@@ -15,11 +15,8 @@ macro_rules! impl_base_currency {
                 }
 
                 #[inline]
-                fn wrapped(&self) -> &Token {
-                    match &self.meta {
-                        Some(weth) => weth,
-                        None => panic!("WRAPPED"),
-                    }
+                fn wrapped(&self) -> Result<&Token, Error> {
+                    self.meta.as_ref().ok_or(Error::NoWrappedToken(self.chain_id))
                 }
             }
         )*
@@ -78,14 +75,28 @@ mod tests {
     #[test]
     fn test_wrapped_returns_robinhood_weth() {
         assert_eq!(
-            Ether::on_chain(4663).wrapped().address,
+            Ether::on_chain(4663).wrapped().unwrap().address,
             address!("0x0Bd7D308f8E1639FAb988df18A8011f41EAcAD73")
         );
     }
 
     #[test]
-    #[should_panic(expected = "WRAPPED")]
-    fn test_wrapped_panics_for_arc() {
-        Ether::on_chain(5042).wrapped();
+    fn test_address_of_arc_has_no_wrapped_token() {
+        assert_eq!(
+            Ether::on_chain(5042).address(),
+            Err(Error::NoWrappedToken(5042))
+        );
+    }
+
+    #[test]
+    fn test_wrapped() {
+        assert_eq!(
+            Ether::on_chain(1).wrapped().unwrap().address,
+            address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")
+        );
+        assert_eq!(
+            Ether::on_chain(5042).wrapped(),
+            Err(Error::NoWrappedToken(5042))
+        );
     }
 }
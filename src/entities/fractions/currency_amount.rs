@@ -1,8 +1,16 @@
 use crate::prelude::*;
 use alloc::string::ToString;
+use alloy_primitives::I256;
+use core::{fmt, ops::Mul};
 use fastnum::i512;
 
 /// Currency amount struct that represents a rational amount of a currency
+///
+/// The constructors and the methods returning a [`Result`], such as [`CurrencyAmount::add`],
+/// keep the raw amount within [`MAX_UINT256`], and so do the `+`, `-`, `*` and `/` operators with
+/// an integer operand, which panic instead. The operators between two currency amounts are the
+/// generic [`FractionLike`] operators: they keep the currency of the left operand without
+/// comparing it to the currency of the right one and do not enforce [`MAX_UINT256`].
 pub type CurrencyAmount<T> = FractionLike<CurrencyMeta<T>>;
 
 /// Struct representing metadata about a currency
@@ -22,21 +30,20 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
         numerator: impl Into<BigInt>,
         denominator: impl Into<BigInt>,
     ) -> Result<Self, Error> {
-        let numerator = numerator.into();
-        let denominator = denominator.into();
-        // Ensure the amount does not exceed MAX_UINT256
-        if numerator.div_floor(denominator) > MAX_UINT256 {
-            return Err(Error::UintOverflow);
-        }
         let exponent = currency.decimals();
-        Ok(FractionBase::new(
+        let amount: Self = FractionBase::try_new(
             numerator,
             denominator,
             CurrencyMeta {
                 currency,
                 decimal_scale: i512!(10).pow(exponent as u32),
             },
-        ))
+        )?;
+        // Ensure the amount does not exceed MAX_UINT256, the denominator is positive by now
+        if amount.quotient() > MAX_UINT256 {
+            return Err(Error::uint_overflow(amount.as_fraction()));
+        }
+        Ok(amount)
     }
 
     /// Returns a new currency amount instance from the unitless amount of token (raw amount)
@@ -46,6 +53,12 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
     }
 
     /// Construct a currency amount with a denominator that is not equal to 0
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroDenominator`] if the denominator is zero, [`Error::Overflow`] if the
+    /// sign of the fraction cannot be normalized and [`Error::UintOverflow`] if the amount exceeds
+    /// [`MAX_UINT256`].
     #[inline]
     pub fn from_fractional_amount(
         currency: T,
@@ -55,10 +68,61 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
         Self::new(currency, numerator, denominator)
     }
 
+    /// Returns a new currency amount from a raw [`U256`] value, which always fits
+    #[inline]
+    pub fn from_u256(currency: T, raw_amount: U256) -> Self {
+        let exponent = currency.decimals();
+        FractionBase::new(
+            raw_amount.to_big_int(),
+            1,
+            CurrencyMeta {
+                currency,
+                decimal_scale: i512!(10).pow(exponent as u32),
+            },
+        )
+    }
+
+    /// Converts the raw amount to a [`U256`], rounding fractional raw units with the given
+    /// rounding strategy
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative and [`Error::UintOverflow`] if the
+    /// rounded amount exceeds [`U256::MAX`].
+    #[inline]
+    pub fn to_u256(&self, rounding: Rounding) -> Result<U256, Error> {
+        self.to_q(0, rounding)
+    }
+
+    /// Returns the raw amount if it is a whole number
+    #[inline]
+    fn whole_raw_amount(&self) -> Result<BigInt, Error> {
+        if !(self.numerator % self.denominator).is_zero() {
+            return Err(Error::not_integer(self.as_fraction()));
+        }
+        Ok(self.numerator / self.denominator)
+    }
+
+    /// Parses a human-readable decimal amount of the currency, e.g. `"1.25"` USDC, scaling it by
+    /// the decimals of the currency. See [`parse_units()`] for the accepted syntax and errors.
+    ///
+    /// ```
+    /// use uniswap_sdk_core::{prelude::*, token};
+    ///
+    /// let usdc = token!(1, "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6, "USDC");
+    /// let amount = CurrencyAmount::from_decimal_str(usdc, "1.25", PrecisionMode::Exact).unwrap();
+    /// assert_eq!(amount.quotient(), BigInt::from(1_250_000));
+    /// ```
+    #[inline]
+    pub fn from_decimal_str(currency: T, value: &str, mode: PrecisionMode) -> Result<Self, Error> {
+        let raw_amount = parse_units(value, currency.decimals(), mode)?;
+        Self::from_raw_amount(currency, raw_amount)
+    }
+
     /// Multiplication of currency amount by another fractional amount
     #[inline]
     pub fn multiply<M: Clone>(&self, other: &impl FractionBase<M>) -> Result<Self, Error> {
-        let multiplied = self.as_fraction() * other.as_fraction();
+        let multiplied = self.as_fraction().checked_mul(&other.as_fraction())?;
         Self::from_fractional_amount(
             self.currency.clone(),
             multiplied.numerator,
@@ -69,7 +133,7 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
     /// Division of currency amount by another fractional amount
     #[inline]
     pub fn divide<M: Clone>(&self, other: &impl FractionBase<M>) -> Result<Self, Error> {
-        let divided = self.as_fraction() / other.as_fraction();
+        let divided = self.as_fraction().checked_div(&other.as_fraction())?;
         Self::from_fractional_amount(
             self.currency.clone(),
             divided.numerator,
@@ -77,32 +141,110 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
         )
     }
 
-    /// Convert the currency amount to a string with exact precision
+    /// Convert the currency amount to a string with exact precision, including fractional raw
+    /// units, e.g. `"0.0000015"` for 1.5 raw units of a currency with 6 decimals.
+    ///
+    /// Terminating values are always rendered exactly. Values whose decimal expansion does not
+    /// terminate, such as a third of a raw unit, are handled according to `non_terminating`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NonTerminating`] for a non-terminating value in
+    /// [`NonTerminating::Reject`] mode and [`Error::MaxDigitsExceeded`] if the repeating
+    /// expansion is longer than allowed in [`NonTerminating::Repeating`] mode.
+    #[inline]
+    pub fn to_exact(&self, non_terminating: NonTerminating) -> Result<String, Error> {
+        // expand the raw amount and shift its digits, since the denominator scaled by the
+        // decimals may not fit in a `BigInt`
+        let decimals = self.currency.decimals() as usize;
+        // a terminating expansion of a denominator below 2^512 has at most 511 fraction digits
+        match self.to_decimal_expansion(BigInt::BITS as usize) {
+            Ok(expansion) if expansion.is_terminating() => {
+                return Ok(expansion.shifted(decimals).to_string());
+            }
+            Ok(_) | Err(Error::MaxDigitsExceeded(_)) => {}
+            Err(e) => return Err(e),
+        }
+        match non_terminating {
+            NonTerminating::Reject => Err(Error::NonTerminating),
+            NonTerminating::Repeating { max_digits } => {
+                let expansion = self.to_decimal_expansion(max_digits)?.shifted(decimals);
+                if expansion.non_repeating.len() + expansion.repeating.len() > max_digits {
+                    return Err(Error::MaxDigitsExceeded(max_digits));
+                }
+                Ok(expansion.to_string())
+            }
+            NonTerminating::Round {
+                decimal_places,
+                rounding,
+            } => {
+                let mut s = String::new();
+                write_fixed_digits(
+                    &mut s,
+                    self.numerator,
+                    self.denominator,
+                    decimals as isize,
+                    decimal_places,
+                    rounding,
+                )?;
+                Ok(s)
+            }
+        }
+    }
+
+    /// Splits the currency amount into its whole raw amount, rounded down, and the remaining
+    /// fraction of a raw unit in `[0, 1)`, so that no sub-unit dust is lost.
+    ///
+    /// ```
+    /// use uniswap_sdk_core::{prelude::*, token};
+    ///
+    /// let usdc = token!(1, "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6, "USDC");
+    /// let amount = CurrencyAmount::from_fractional_amount(usdc, 10, 3).unwrap();
+    /// let (raw, dust) = amount.to_exact_raw();
+    /// assert_eq!(raw, BigInt::from(3));
+    /// assert_eq!(dust, Fraction::new(1, 3));
+    /// ```
     #[inline]
-    pub fn to_exact(&self) -> String {
-        self.quotient()
-            .to_big_decimal()
-            .div(self.decimal_scale.to_big_decimal())
-            .to_string()
+    #[must_use]
+    pub fn to_exact_raw(&self) -> (BigInt, Fraction) {
+        let raw = self.quotient();
+        let dust = Fraction::new(self.numerator - raw * self.denominator, self.denominator);
+        (raw, dust)
     }
 
     /// Addition of another currency amount to the current amount
+    ///
+    /// Unlike the `+` operator between two currency amounts, this checks that the currencies
+    /// match and that the result does not exceed [`MAX_UINT256`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CurrencyMismatch`] if the currencies differ and
+    /// [`Error::UintOverflow`] if the result exceeds [`MAX_UINT256`].
     #[inline]
     pub fn add(&self, other: &Self) -> Result<Self, Error> {
         if !self.currency.equals(&other.currency) {
-            return Err(Error::CurrencyMismatch);
+            return Err(Error::currency_mismatch(&self.currency, &other.currency));
         }
-        let added = self.as_fraction() + other.as_fraction();
+        let added = self.as_fraction().checked_add(&other.as_fraction())?;
         Self::from_fractional_amount(self.currency.clone(), added.numerator, added.denominator)
     }
 
     /// Subtraction of another currency amount from the current amount
+    ///
+    /// Unlike the `-` operator between two currency amounts, this checks that the currencies
+    /// match and that the result does not exceed [`MAX_UINT256`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CurrencyMismatch`] if the currencies differ and
+    /// [`Error::UintOverflow`] if the result exceeds [`MAX_UINT256`].
     #[inline]
     pub fn subtract(&self, other: &Self) -> Result<Self, Error> {
         if !self.currency.equals(&other.currency) {
-            return Err(Error::CurrencyMismatch);
+            return Err(Error::currency_mismatch(&self.currency, &other.currency));
         }
-        let subtracted = self.as_fraction() - other.as_fraction();
+        let subtracted = self.as_fraction().checked_sub(&other.as_fraction())?;
         Self::from_fractional_amount(
             self.currency.clone(),
             subtracted.numerator,
@@ -110,6 +252,80 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
         )
     }
 
+    /// Returns the amount in units of the currency, i.e. the raw amount divided by the decimal
+    /// scale
+    ///
+    /// Returns [`Error::Overflow`] if the scaled denominator does not fit in [`BigInt`].
+    #[inline]
+    fn to_units(&self) -> Result<Fraction, Error> {
+        self.as_fraction()
+            .checked_div(&Fraction::new(self.decimal_scale, 1))
+    }
+
+    /// Write the currency amount with a specified number of significant digits into a
+    /// [`fmt::Write`] buffer without allocating
+    #[inline]
+    pub fn write_significant(
+        &self,
+        w: &mut impl fmt::Write,
+        significant_digits: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        if significant_digits == 0 {
+            return Err(Error::ZeroSignificantDigits);
+        }
+        Ok(write_significant_digits(
+            w,
+            self.numerator,
+            self.denominator,
+            self.currency.decimals() as isize,
+            significant_digits as usize,
+            rounding.unwrap_or(Rounding::RoundDown),
+        )?)
+    }
+
+    /// Write the currency amount with a fixed number of decimal places into a [`fmt::Write`]
+    /// buffer without allocating
+    #[inline]
+    pub fn write_fixed(
+        &self,
+        w: &mut impl fmt::Write,
+        decimal_places: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        if decimal_places > self.currency.decimals() {
+            return Err(Error::ExcessDecimalPlaces {
+                decimal_places,
+                decimals: self.currency.decimals(),
+            });
+        }
+        Ok(write_fixed_digits(
+            w,
+            self.numerator,
+            self.denominator,
+            self.currency.decimals() as isize,
+            decimal_places as usize,
+            rounding.unwrap_or(Rounding::RoundDown),
+        )?)
+    }
+
+    /// Write the currency amount using a [`NumberFormat`] into a [`fmt::Write`] buffer without
+    /// allocating
+    #[inline]
+    pub fn write_formatted(
+        &self,
+        w: &mut impl fmt::Write,
+        format: &NumberFormat,
+    ) -> Result<(), Error> {
+        format.write(w, &self.to_units()?)
+    }
+
+    /// Convert the currency amount to a string using a [`NumberFormat`]
+    #[inline]
+    pub fn to_formatted(&self, format: &NumberFormat) -> Result<String, Error> {
+        format.format(&self.to_units()?)
+    }
+
     /// Convert the currency amount to a string with a specified number of significant digits
     #[inline]
     pub fn to_significant(
@@ -117,10 +333,9 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
         significant_digits: u8,
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
-        (self.as_fraction() / Fraction::new(self.decimal_scale, 1)).to_significant(
-            significant_digits,
-            Some(rounding.unwrap_or(Rounding::RoundDown)),
-        )
+        let mut s = String::new();
+        self.write_significant(&mut s, significant_digits, rounding)?;
+        Ok(s)
     }
 
     /// Convert the currency amount to a string with a fixed number of decimal places
@@ -130,42 +345,460 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
         decimal_places: u8,
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
-        if decimal_places > self.currency.decimals() {
-            return Err(Error::Invalid("DECIMALS"));
-        }
-        Ok(
-            (self.as_fraction() / Fraction::new(self.decimal_scale, 1)).to_fixed(
-                decimal_places,
-                Some(rounding.unwrap_or(Rounding::RoundDown)),
-            ),
-        )
+        let mut s = String::new();
+        self.write_fixed(&mut s, decimal_places, rounding)?;
+        Ok(s)
+    }
+
+    /// Returns a helper that displays the currency amount followed by its currency symbol, e.g.
+    /// `"1.5 USDC"`.
+    #[inline]
+    pub const fn display_with_symbol(&self) -> DisplayWithSymbol<'_, T> {
+        DisplayWithSymbol(self)
     }
 
     /// Wrap the currency amount if the currency is not native
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoWrappedToken`] if the currency is native and has no wrapped token.
     #[inline]
     pub fn wrapped(&self) -> Result<CurrencyAmount<&Token>, Error> {
         CurrencyAmount::from_fractional_amount(
-            self.currency.wrapped(),
+            self.currency.wrapped()?,
             self.numerator(),
             self.denominator(),
         )
     }
 
     /// Wrap the currency amount if the currency is not native
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoWrappedToken`] if the currency is native and has no wrapped token.
     #[inline]
     pub fn wrapped_owned(&self) -> Result<CurrencyAmount<Token>, Error> {
         CurrencyAmount::from_fractional_amount(
-            self.currency.wrapped().clone(),
+            self.currency.wrapped()?.clone(),
             self.numerator(),
             self.denominator(),
         )
     }
+
+    /// Applies a fraction operation to the amount and a whole number of raw units, enforcing the
+    /// [`MAX_UINT256`] bound of the result
+    #[inline]
+    fn apply_int(
+        &self,
+        other: BigInt,
+        op: fn(&Fraction, &Fraction) -> Result<Fraction, Error>,
+    ) -> Result<Self, Error> {
+        let result = op(&self.as_fraction(), &Fraction::new(other, 1))?;
+        Self::from_fractional_amount(self.currency.clone(), result.numerator, result.denominator)
+    }
+
+    /// Returns the amount received when the amount is sold, i.e. transferred into a pool, after
+    /// deducting the sell fee of a fee-on-transfer token.
+    ///
+    /// Transfers move whole raw units, so the amount is rounded down first. The fee is then
+    /// truncated like on-chain fee-on-transfer tokens do, i.e. `amount - amount * fee / 10000`
+    /// is received. Native currencies and tokens without fees are returned unchanged apart from
+    /// the rounding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative and [`Error::InvalidFeeBps`] if the
+    /// fee exceeds 100%.
+    #[inline]
+    pub fn after_sell_fee(&self) -> Result<Self, Error> {
+        self.after_fee(self.transfer_fees()?.1)
+    }
+
+    /// Returns the amount received when the amount is bought, i.e. transferred out of a pool,
+    /// after deducting the buy fee of a fee-on-transfer token.
+    ///
+    /// Rounds like [`CurrencyAmount::after_sell_fee`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative and [`Error::InvalidFeeBps`] if the
+    /// fee exceeds 100%.
+    #[inline]
+    pub fn after_buy_fee(&self) -> Result<Self, Error> {
+        self.after_fee(self.transfer_fees()?.0)
+    }
+
+    /// Returns the smallest amount a pool must send so that at least this amount is received
+    /// after the buy fee, the inverse of [`CurrencyAmount::after_buy_fee`].
+    ///
+    /// The amount is the target to be received, so a desired output amount can be grossed up in
+    /// place, e.g. `target_received.gross_up_for_buy_fee()`. Fractional raw units of the target
+    /// are rounded up.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative, [`Error::InvalidFeeBps`] if the fee
+    /// is 100% or more and [`Error::UintOverflow`] if the result exceeds [`MAX_UINT256`].
+    #[inline]
+    pub fn gross_up_for_buy_fee(&self) -> Result<Self, Error> {
+        self.gross_up(self.transfer_fees()?.0)
+    }
+
+    /// Returns the smallest amount that must be sold so that at least this amount reaches the
+    /// pool after the sell fee, the inverse of [`CurrencyAmount::after_sell_fee`].
+    ///
+    /// The amount is the target to reach the pool and rounds like
+    /// [`CurrencyAmount::gross_up_for_buy_fee`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative, [`Error::InvalidFeeBps`] if the fee
+    /// is 100% or more and [`Error::UintOverflow`] if the result exceeds [`MAX_UINT256`].
+    #[inline]
+    pub fn gross_up_for_sell_fee(&self) -> Result<Self, Error> {
+        self.gross_up(self.transfer_fees()?.1)
+    }
+
+    /// Returns the minimum amount to receive for a quoted output amount within a slippage
+    /// tolerance, i.e. `amount / (1 + slippage)`.
+    ///
+    /// The result is rounded down to a whole raw amount, matching `minimumAmountOut` of the
+    /// Uniswap TypeScript SDK.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative and [`Error::InvalidSlippage`] if the
+    /// tolerance is negative or exceeds 100%.
+    #[inline]
+    pub fn minimum_received(&self, slippage: &Percent) -> Result<Self, Error> {
+        let factor = Fraction::ONE.checked_add(&check_slippage(slippage)?)?;
+        let amount = Fraction::new(self.transfer_amount()?, 1).checked_div(&factor)?;
+        Self::from_raw_amount(self.currency.clone(), amount.quotient())
+    }
+
+    /// Returns the maximum amount to send for a quoted input amount within a slippage
+    /// tolerance, i.e. `amount * (1 + slippage)`.
+    ///
+    /// The result is rounded down to a whole raw amount, so it never exceeds the tolerance,
+    /// matching `maximumAmountIn` of the Uniswap TypeScript SDK.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative, [`Error::InvalidSlippage`] if the
+    /// tolerance is negative or exceeds 100% and [`Error::UintOverflow`] if the result exceeds
+    /// [`MAX_UINT256`].
+    #[inline]
+    pub fn maximum_sent(&self, slippage: &Percent) -> Result<Self, Error> {
+        let factor = Fraction::ONE.checked_add(&check_slippage(slippage)?)?;
+        let amount = Fraction::new(self.transfer_amount()?, 1).checked_mul(&factor)?;
+        Self::from_raw_amount(self.currency.clone(), amount.quotient())
+    }
+
+    /// Returns the slippage-adjusted bound of the amount that is not fixed by the trade type:
+    /// the [minimum received](CurrencyAmount::minimum_received) output of an
+    /// [`TradeType::ExactInput`] trade or the [maximum sent](CurrencyAmount::maximum_sent) input of
+    /// an [`TradeType::ExactOutput`] trade.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the amount is negative, [`Error::InvalidSlippage`] if the
+    /// tolerance is negative or exceeds 100% and [`Error::UintOverflow`] if the result exceeds
+    /// [`MAX_UINT256`].
+    #[inline]
+    pub fn slippage_adjusted(
+        &self,
+        trade_type: TradeType,
+        slippage: &Percent,
+    ) -> Result<Self, Error> {
+        match trade_type {
+            TradeType::ExactInput => self.minimum_received(slippage),
+            TradeType::ExactOutput => self.maximum_sent(slippage),
+        }
+    }
+
+    /// Returns the buy and sell fees of the currency in basis points, zero for a native currency
+    #[inline]
+    fn transfer_fees(&self) -> Result<(u64, u64), Error> {
+        if self.currency.is_native() {
+            return Ok((0, 0));
+        }
+        let token = self.currency.wrapped()?;
+        Ok((token.buy_fee_bps, token.sell_fee_bps))
+    }
+
+    /// Returns the whole raw amount, rejecting negative amounts
+    #[inline]
+    fn transfer_amount(&self) -> Result<BigInt, Error> {
+        if self.numerator.is_negative() {
+            return Err(Error::negative(self.as_fraction()));
+        }
+        Ok(self.quotient())
+    }
+
+    /// Deducts a truncated fee from the whole raw amount
+    #[inline]
+    fn after_fee(&self, fee_bps: u64) -> Result<Self, Error> {
+        if fee_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidFeeBps(fee_bps));
+        }
+        let amount = self.transfer_amount()?;
+        let fee = amount * bps(fee_bps) / bps(BPS_DENOMINATOR);
+        Self::from_raw_amount(self.currency.clone(), amount - fee)
+    }
+
+    /// Returns the smallest whole raw amount that is at least this amount after deducting a
+    /// truncated fee
+    #[inline]
+    fn gross_up(&self, fee_bps: u64) -> Result<Self, Error> {
+        if fee_bps >= BPS_DENOMINATOR {
+            return Err(Error::InvalidFeeBps(fee_bps));
+        }
+        if self.numerator.is_negative() {
+            return Err(Error::negative(self.as_fraction()));
+        }
+        let target = self.numerator().div_ceil(self.denominator());
+        if target.is_zero() {
+            return Self::from_raw_amount(self.currency.clone(), target);
+        }
+        // `g - floor(g * fee / 10000) >= target` holds iff `g > (target - 1) * 10000 / (10000 -
+        // fee)`
+        let gross = (target - BigInt::ONE) * bps(BPS_DENOMINATOR) / bps(BPS_DENOMINATOR - fee_bps)
+            + BigInt::ONE;
+        Self::from_raw_amount(self.currency.clone(), gross)
+    }
+}
+
+/// Returns the slippage tolerance as a [`Fraction`] if it is between 0% and 100%
+#[inline]
+fn check_slippage(slippage: &Percent) -> Result<Fraction, Error> {
+    let fraction = slippage.as_fraction();
+    if fraction < Fraction::ZERO || fraction > Fraction::ONE {
+        return Err(Error::invalid_slippage(slippage.clone()));
+    }
+    Ok(fraction)
+}
+
+/// Converts basis points to a [`BigInt`]
+#[inline]
+const fn bps(bps: u64) -> BigInt {
+    BigInt::from_bits(BigUint::from_u64(bps))
+}
+
+impl<T: BaseCurrency> fmt::Display for CurrencyAmount<T> {
+    /// Formats the currency amount in units of the currency, rounding down.
+    ///
+    /// `{:.N}` renders `N` fixed decimal places, `{:#.N}` renders `N` significant digits and `{}`
+    /// renders [`DISPLAY_SIGNIFICANT_DIGITS`] significant digits.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // shifting the digits of the raw amount never overflows, unlike dividing by the scale
+        fmt_decimal(
+            f,
+            self.numerator,
+            self.denominator,
+            self.currency.decimals() as isize,
+            Rounding::RoundDown,
+        )
+    }
+}
+
+macro_rules! impl_int_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:path) => {
+        impl_int_op!(
+            @rhs $trait, $method, $assign_trait, $assign_method, $op,
+            BigInt => |other| other,
+            u64 => |other| bps(other),
+            i128 => |other| BigInt::from_i128(other).unwrap_or_else(|e| panic!("{e}")),
+        );
+    };
+    (
+        @rhs $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:path,
+        $($Rhs:ty => |$other:ident| $int:expr),+ $(,)?
+    ) => {
+        $(
+            impl<T: BaseCurrency> core::ops::$trait<$Rhs> for CurrencyAmount<T> {
+                type Output = Self;
+
+                /// Applies the operation to the raw amount.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the result overflows, exceeds [`MAX_UINT256`] or the divisor is zero.
+                #[inline]
+                fn $method(self, $other: $Rhs) -> Self::Output {
+                    self.apply_int($int, $op).unwrap_or_else(|e| panic!("{e}"))
+                }
+            }
+
+            impl<T: BaseCurrency> core::ops::$assign_trait<$Rhs> for CurrencyAmount<T> {
+                /// Applies the operation to the raw amount.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the result overflows, exceeds [`MAX_UINT256`] or the divisor is zero.
+                #[inline]
+                fn $assign_method(&mut self, $other: $Rhs) {
+                    *self = self.apply_int($int, $op).unwrap_or_else(|e| panic!("{e}"));
+                }
+            }
+        )+
+    };
+}
+
+impl_int_op!(Add, add, AddAssign, add_assign, Fraction::checked_add);
+impl_int_op!(Sub, sub, SubAssign, sub_assign, Fraction::checked_sub);
+impl_int_op!(Mul, mul, MulAssign, mul_assign, Fraction::checked_mul);
+impl_int_op!(Div, div, DivAssign, div_assign, Fraction::checked_div);
+
+impl<T: BaseCurrency> Mul<Percent> for CurrencyAmount<T> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows or exceeds [`MAX_UINT256`]. Use
+    /// [`CurrencyAmount::multiply`] to handle these cases.
+    #[inline]
+    fn mul(self, percent: Percent) -> Self::Output {
+        self * &percent
+    }
+}
+
+impl<T: BaseCurrency> Mul<&Percent> for CurrencyAmount<T> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows or exceeds [`MAX_UINT256`]. Use
+    /// [`CurrencyAmount::multiply`] to handle these cases.
+    #[inline]
+    fn mul(self, percent: &Percent) -> Self::Output {
+        self.multiply(percent).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<TBase: BaseCurrency, TQuote: BaseCurrency> Mul<Price<TBase, TQuote>>
+    for CurrencyAmount<TBase>
+{
+    type Output = CurrencyAmount<TQuote>;
+
+    /// Converts the amount to the quote currency of the price.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the currency of the amount is not the base currency of the price or if the result
+    /// overflows. Use [`Price::quote`] to handle these cases.
+    #[inline]
+    fn mul(self, price: Price<TBase, TQuote>) -> Self::Output {
+        self * &price
+    }
+}
+
+impl<TBase: BaseCurrency, TQuote: BaseCurrency> Mul<&Price<TBase, TQuote>>
+    for CurrencyAmount<TBase>
+{
+    type Output = CurrencyAmount<TQuote>;
+
+    /// Converts the amount to the quote currency of the price.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the currency of the amount is not the base currency of the price or if the result
+    /// overflows. Use [`Price::quote`] to handle these cases.
+    #[inline]
+    fn mul(self, price: &Price<TBase, TQuote>) -> Self::Output {
+        price.quote(&self).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<T: BaseCurrency> TryFrom<&CurrencyAmount<T>> for U256 {
+    type Error = Error;
+
+    /// Converts a whole raw amount losslessly, see [`CurrencyAmount::to_u256`] to round
+    /// fractional raw units.
+    ///
+    /// Returns [`Error::NotInteger`] if the amount has fractional raw units,
+    /// [`Error::Negative`] if it is negative and [`Error::UintOverflow`] if it exceeds
+    /// [`MAX_UINT256`].
+    #[inline]
+    fn try_from(amount: &CurrencyAmount<T>) -> Result<Self, Self::Error> {
+        let raw_amount = amount.whole_raw_amount()?;
+        if raw_amount.is_negative() {
+            return Err(Error::negative(amount.as_fraction()));
+        }
+        if raw_amount > MAX_UINT256 {
+            return Err(Error::uint_overflow(amount.as_fraction()));
+        }
+        Ok(Self::from_big_int(raw_amount))
+    }
+}
+
+impl<T: BaseCurrency> TryFrom<&CurrencyAmount<T>> for I256 {
+    type Error = Error;
+
+    /// Converts a whole raw amount losslessly.
+    ///
+    /// Returns [`Error::NotInteger`] if the amount has fractional raw units and
+    /// [`Error::Overflow`] if it is outside the range of [`I256`].
+    #[inline]
+    fn try_from(amount: &CurrencyAmount<T>) -> Result<Self, Self::Error> {
+        let raw_amount = amount.whole_raw_amount()?;
+        if raw_amount < Self::MIN.to_big_int() || raw_amount > Self::MAX.to_big_int() {
+            return Err(Error::Overflow(Self::BITS as u32));
+        }
+        Ok(Self::from_big_int(raw_amount))
+    }
+}
+
+impl<T: BaseCurrency> TryFrom<&CurrencyAmount<T>> for u128 {
+    type Error = Error;
+
+    /// Converts a whole raw amount losslessly.
+    ///
+    /// Returns [`Error::NotInteger`] if the amount has fractional raw units, [`Error::Negative`]
+    /// if it is negative and [`Error::Overflow`] if it exceeds [`u128::MAX`].
+    #[inline]
+    fn try_from(amount: &CurrencyAmount<T>) -> Result<Self, Self::Error> {
+        let raw_amount = amount.whole_raw_amount()?;
+        if raw_amount.is_negative() {
+            return Err(Error::negative(amount.as_fraction()));
+        }
+        raw_amount
+            .to_u128()
+            .map_err(|_| Error::Overflow(u128::BITS))
+    }
+}
+
+/// Displays a [`CurrencyAmount`] followed by its currency symbol, if any.
+///
+/// Created by [`CurrencyAmount::display_with_symbol`].
+#[derive(Debug)]
+pub struct DisplayWithSymbol<'a, T: BaseCurrency>(&'a CurrencyAmount<T>);
+
+impl<T: BaseCurrency> Clone for DisplayWithSymbol<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: BaseCurrency> Copy for DisplayWithSymbol<'_, T> {}
+
+impl<T: BaseCurrency> fmt::Display for DisplayWithSymbol<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)?;
+        match self.0.currency.symbol() {
+            Some(symbol) => write!(f, " {symbol}"),
+            None => Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::token;
+    use alloc::format;
     use lazy_static::lazy_static;
 
     // Constants for testing
@@ -184,6 +817,317 @@ mod tests {
         assert_eq!(amount.quotient(), 100.into());
     }
 
+    #[test]
+    fn test_from_decimal_str() {
+        let amount =
+            CurrencyAmount::from_decimal_str(TOKEN18.clone(), "1.5", PrecisionMode::Exact).unwrap();
+        assert_eq!(amount.quotient(), BigInt::from(15) * BigInt::TEN.pow(17));
+        assert_eq!(amount.to_exact(NonTerminating::Reject).unwrap(), "1.5");
+        let padded = format!("1.5{}", "0".repeat(200));
+        assert_eq!(
+            CurrencyAmount::from_decimal_str(TOKEN18.clone(), &padded, PrecisionMode::Exact),
+            Ok(amount)
+        );
+        let amount =
+            CurrencyAmount::from_decimal_str(TOKEN0.clone(), "2e3", PrecisionMode::Exact).unwrap();
+        assert_eq!(amount.quotient(), BigInt::from(2000));
+        assert_eq!(
+            CurrencyAmount::from_decimal_str(TOKEN0.clone(), "0.5", PrecisionMode::Exact),
+            Err(Error::ExcessPrecision { decimals: 0 })
+        );
+        let amount = CurrencyAmount::from_decimal_str(
+            TOKEN0.clone(),
+            "0.5",
+            PrecisionMode::Round(Rounding::RoundHalfUp),
+        )
+        .unwrap();
+        assert_eq!(amount.quotient(), BigInt::ONE);
+        assert_eq!(
+            CurrencyAmount::from_decimal_str(TOKEN18.clone(), "1e60", PrecisionMode::Exact),
+            Err(Error::uint_overflow(Fraction::new(BigInt::TEN.pow(78), 1)))
+        );
+    }
+
+    #[test]
+    fn test_fee_on_transfer() {
+        let tax = Token::new(1, Address::with_last_byte(2), 18, None, None, 300, 500);
+        let amount = CurrencyAmount::from_raw_amount(tax.clone(), 1001).unwrap();
+        // the fees 1001 * 5% = 50.05 and 1001 * 3% = 30.03 are truncated
+        assert_eq!(
+            amount.after_sell_fee().unwrap().quotient(),
+            BigInt::from(951)
+        );
+        assert_eq!(
+            amount.after_buy_fee().unwrap().quotient(),
+            BigInt::from(971)
+        );
+        // fractional raw units are not transferred
+        let amount = CurrencyAmount::from_fractional_amount(tax.clone(), 2001, 2).unwrap();
+        assert_eq!(
+            amount.after_sell_fee().unwrap().quotient(),
+            BigInt::from(950)
+        );
+
+        let target = CurrencyAmount::from_raw_amount(tax.clone(), 951).unwrap();
+        let gross = target.gross_up_for_sell_fee().unwrap();
+        assert_eq!(gross.quotient(), BigInt::from(1001));
+        assert_eq!(gross.after_sell_fee().unwrap(), target);
+        let target = CurrencyAmount::from_raw_amount(tax.clone(), 971).unwrap();
+        assert_eq!(
+            target.gross_up_for_buy_fee().unwrap().quotient(),
+            BigInt::from(1001)
+        );
+
+        let zero = CurrencyAmount::from_raw_amount(tax, 0).unwrap();
+        assert_eq!(zero.gross_up_for_sell_fee().unwrap(), zero);
+        assert_eq!(zero.after_sell_fee().unwrap(), zero);
+    }
+
+    #[test]
+    fn test_gross_up_is_minimal() {
+        for fee_bps in [0, 1, 250, 3333, 5000, 9999] {
+            let token = Token::new(1, Address::with_last_byte(2), 0, None, None, fee_bps, 0);
+            for target in 0..200 {
+                let target = CurrencyAmount::from_raw_amount(token.clone(), target).unwrap();
+                let gross = target.gross_up_for_buy_fee().unwrap();
+                assert!(gross.after_buy_fee().unwrap() >= target);
+                if !gross.is_zero() {
+                    let less =
+                        gross.subtract(&CurrencyAmount::from_raw_amount(token.clone(), 1).unwrap());
+                    assert!(less.unwrap().after_buy_fee().unwrap() < target);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fee_on_transfer_errors() {
+        let untaxed = CurrencyAmount::from_raw_amount(Ether::on_chain(1), 1001).unwrap();
+        assert_eq!(untaxed.after_sell_fee().unwrap(), untaxed);
+        assert_eq!(untaxed.gross_up_for_buy_fee().unwrap(), untaxed);
+
+        let full = Token::new(
+            1,
+            Address::with_last_byte(2),
+            18,
+            None,
+            None,
+            10_000,
+            10_001,
+        );
+        let amount = CurrencyAmount::from_raw_amount(full, 1001).unwrap();
+        assert!(amount.after_buy_fee().unwrap().is_zero());
+        assert_eq!(
+            amount.gross_up_for_buy_fee(),
+            Err(Error::InvalidFeeBps(10_000))
+        );
+        assert_eq!(amount.after_sell_fee(), Err(Error::InvalidFeeBps(10_001)));
+
+        let negative = CurrencyAmount::from_raw_amount(TOKEN18.clone(), -1).unwrap();
+        let error = Error::negative(Fraction::new(-1, 1));
+        assert_eq!(negative.after_sell_fee(), Err(error.clone()));
+        assert_eq!(negative.gross_up_for_sell_fee(), Err(error));
+        // also when the amount is less than one raw unit
+        let negative = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), -1, 2).unwrap();
+        let error = Error::negative(Fraction::new(-1, 2));
+        assert_eq!(negative.after_sell_fee(), Err(error.clone()));
+        assert_eq!(negative.gross_up_for_sell_fee(), Err(error.clone()));
+        assert_eq!(negative.gross_up_for_buy_fee(), Err(error));
+    }
+
+    // Parity with `Trade.minimumAmountOut` and `Trade.maximumAmountIn` of the v3 TypeScript SDK,
+    // whose exact input trade of 100 token0 outputs 69 and exact output trade of 100 token2
+    // inputs 156
+    #[test]
+    fn test_minimum_received() {
+        let amount_out = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 69).unwrap();
+        let minimum = |slippage| amount_out.minimum_received(&slippage).unwrap().quotient();
+        assert_eq!(minimum(Percent::new(0, 100)), BigInt::from(69));
+        assert_eq!(minimum(Percent::new(5, 100)), BigInt::from(65));
+        assert_eq!(minimum(Percent::new(100, 100)), BigInt::from(34));
+        assert_eq!(
+            amount_out.slippage_adjusted(TradeType::ExactInput, &Percent::new(5, 100)),
+            amount_out.minimum_received(&Percent::new(5, 100))
+        );
+    }
+
+    #[test]
+    fn test_maximum_sent() {
+        let amount_in = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 156).unwrap();
+        let maximum = |slippage| amount_in.maximum_sent(&slippage).unwrap().quotient();
+        assert_eq!(maximum(Percent::new(0, 100)), BigInt::from(156));
+        assert_eq!(maximum(Percent::new(5, 100)), BigInt::from(163));
+        assert_eq!(maximum(Percent::new(100, 100)), BigInt::from(312));
+        assert_eq!(
+            amount_in.slippage_adjusted(TradeType::ExactOutput, &Percent::new(5, 100)),
+            amount_in.maximum_sent(&Percent::new(5, 100))
+        );
+        let max = CurrencyAmount::from_raw_amount(TOKEN18.clone(), MAX_UINT256).unwrap();
+        assert_eq!(
+            max.maximum_sent(&Percent::new(1, 100)),
+            Err(Error::uint_overflow(Fraction::new(
+                MAX_UINT256 * BigInt::from(101) / BigInt::from(100),
+                1
+            )))
+        );
+    }
+
+    #[test]
+    fn test_invalid_slippage() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 100).unwrap();
+        for slippage in [Percent::new(-1, 100), Percent::new(200, 100)] {
+            let error = Error::invalid_slippage(slippage.clone());
+            assert_eq!(amount.minimum_received(&slippage), Err(error.clone()));
+            assert_eq!(amount.maximum_sent(&slippage), Err(error));
+        }
+    }
+
+    #[test]
+    fn test_slippage_rejects_negative_amounts() {
+        let slippage = Percent::new(1, 100);
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), -1).unwrap();
+        let error = Error::negative(Fraction::new(-1, 1));
+        assert_eq!(amount.minimum_received(&slippage), Err(error.clone()));
+        assert_eq!(amount.maximum_sent(&slippage), Err(error));
+
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), -1, 2).unwrap();
+        let error = Error::negative(Fraction::new(-1, 2));
+        assert_eq!(amount.minimum_received(&slippage), Err(error.clone()));
+        assert_eq!(amount.maximum_sent(&slippage), Err(error));
+    }
+
+    #[test]
+    fn test_u256_conversions() {
+        let amount = CurrencyAmount::from_u256(TOKEN18.clone(), U256::MAX);
+        assert_eq!(amount.quotient(), MAX_UINT256);
+        assert_eq!(amount.to_u256(Rounding::RoundDown), Ok(U256::MAX));
+        assert_eq!(U256::try_from(&amount), Ok(U256::MAX));
+        assert_eq!(amount.currency.decimals(), 18);
+
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 7, 2).unwrap();
+        assert_eq!(amount.to_u256(Rounding::RoundDown), Ok(U256::from(3)));
+        assert_eq!(amount.to_u256(Rounding::RoundUp), Ok(U256::from(4)));
+        assert_eq!(
+            U256::try_from(&amount),
+            Err(Error::not_integer(Fraction::new(7, 2)))
+        );
+
+        let amount = CurrencyAmount::from_fractional_amount(
+            TOKEN18.clone(),
+            MAX_UINT256 * BigInt::TWO + BigInt::ONE,
+            2,
+        )
+        .unwrap();
+        assert_eq!(amount.to_u256(Rounding::RoundDown), Ok(U256::MAX));
+        assert_eq!(
+            amount.to_u256(Rounding::RoundUp),
+            Err(Error::uint_overflow(amount.as_fraction()))
+        );
+
+        let negative = CurrencyAmount::from_raw_amount(TOKEN18.clone(), -1).unwrap();
+        let error = Error::negative(Fraction::new(-1, 1));
+        assert_eq!(negative.to_u256(Rounding::RoundDown), Err(error.clone()));
+        assert_eq!(U256::try_from(&negative), Err(error));
+        let negative = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), -1, 2).unwrap();
+        let error = Error::negative(Fraction::new(-1, 2));
+        assert_eq!(negative.to_u256(Rounding::RoundDown), Err(error.clone()));
+        assert_eq!(negative.to_u256(Rounding::RoundHalfDown), Err(error));
+
+        // the fraction operators do not enforce the bound of currency amounts
+        let max = CurrencyAmount::from_u256(TOKEN18.clone(), U256::MAX);
+        let doubled = max.clone() + max;
+        assert_eq!(
+            U256::try_from(&doubled),
+            Err(Error::uint_overflow(Fraction::new(
+                MAX_UINT256 * BigInt::TWO,
+                1
+            )))
+        );
+    }
+
+    #[test]
+    fn test_integer_conversions() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), -42).unwrap();
+        assert_eq!(I256::try_from(&amount), Ok(I256::try_from(-42).unwrap()));
+        assert_eq!(
+            u128::try_from(&amount),
+            Err(Error::negative(Fraction::new(-42, 1)))
+        );
+
+        let amount = CurrencyAmount::from_u256(TOKEN18.clone(), U256::from(u128::MAX));
+        assert_eq!(u128::try_from(&amount), Ok(u128::MAX));
+        assert_eq!(
+            I256::try_from(&amount),
+            Ok(I256::try_from(u128::MAX).unwrap())
+        );
+        let amount = amount.add(&CurrencyAmount::from_raw_amount(TOKEN18.clone(), 1).unwrap());
+        assert_eq!(
+            u128::try_from(&amount.unwrap()),
+            Err(Error::Overflow(u128::BITS))
+        );
+
+        let amount = CurrencyAmount::from_u256(TOKEN18.clone(), U256::MAX);
+        assert_eq!(I256::try_from(&amount), Err(Error::Overflow(256)));
+        let amount = CurrencyAmount::from_u256(TOKEN18.clone(), U256::from(1) << 255);
+        assert_eq!(I256::try_from(&amount), Err(Error::Overflow(256)));
+        let amount =
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), -(BigInt::ONE << 255_u32)).unwrap();
+        assert_eq!(I256::try_from(&amount), Ok(I256::MIN));
+
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, 3).unwrap();
+        let error = Error::not_integer(Fraction::new(1, 3));
+        assert_eq!(I256::try_from(&amount), Err(error.clone()));
+        assert_eq!(u128::try_from(&amount), Err(error));
+    }
+
+    #[test]
+    fn test_add_and_subtract_check_currency_and_bound() {
+        let other = Token::new(1, Address::with_last_byte(2), 18, None, None, 0, 0);
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 1).unwrap();
+        let mismatched = CurrencyAmount::from_raw_amount(other.clone(), 1).unwrap();
+        let error = Error::currency_mismatch(&*TOKEN18, &other);
+        assert_eq!(amount.add(&mismatched), Err(error.clone()));
+        assert_eq!(amount.subtract(&mismatched), Err(error));
+        let max = CurrencyAmount::from_u256(TOKEN18.clone(), U256::MAX);
+        assert_eq!(
+            max.add(&amount),
+            Err(Error::uint_overflow(Fraction::new(
+                MAX_UINT256 + BigInt::ONE,
+                1
+            )))
+        );
+        assert_eq!(
+            max.subtract(&amount).unwrap().quotient(),
+            MAX_UINT256 - BigInt::ONE
+        );
+
+        // the operators between two amounts check neither
+        let sum = amount.clone() + mismatched;
+        assert!(sum.currency.equals(&*TOKEN18));
+        assert_eq!(sum.quotient(), BigInt::TWO);
+        assert_eq!((max + amount).quotient(), MAX_UINT256 + BigInt::ONE);
+    }
+
+    #[test]
+    fn test_integer_operators() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 10).unwrap();
+        assert_eq!((amount.clone() * 3_u64).quotient(), BigInt::from(30));
+        assert_eq!((amount.clone() + BigInt::ONE).quotient(), BigInt::from(11));
+        assert_eq!((amount.clone() - 20_i128).quotient(), BigInt::from(-10));
+        let mut divided = amount;
+        divided /= 4_u64;
+        assert_eq!(
+            divided.to_exact_raw(),
+            (BigInt::from(2), Fraction::new(1, 2))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds U256::MAX")]
+    fn integer_operators_enforce_max_uint256() {
+        let _ = CurrencyAmount::from_u256(TOKEN18.clone(), U256::MAX) * 2_u64;
+    }
+
     #[test]
     fn test_quotient() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 100)
@@ -211,14 +1155,14 @@ mod tests {
     fn test_token_amount_exceeds_max_uint256() {
         let result =
             CurrencyAmount::from_raw_amount(TOKEN18.clone(), MAX_UINT256 + BigInt::from(1));
-        assert!(matches!(result, Err(Error::UintOverflow)));
+        assert!(matches!(result, Err(Error::UintOverflow(_))));
     }
 
     #[test]
     fn test_token_amount_quotient_exceeds_max_uint256() {
         let numerator: BigInt = (MAX_UINT256 + BigInt::from(1)) * BigInt::from(2);
         let result = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), numerator, 2);
-        assert!(matches!(result, Err(Error::UintOverflow)));
+        assert!(matches!(result, Err(Error::UintOverflow(_))));
     }
 
     #[test]
@@ -228,6 +1172,24 @@ mod tests {
         assert_eq!(amount.numerator(), numerator);
     }
 
+    #[test]
+    fn test_token_amount_zero_denominator() {
+        assert_eq!(
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, 0),
+            Err(Error::ZeroDenominator)
+        );
+        assert_eq!(
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, BigInt::MIN),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+    }
+
+    #[test]
+    fn test_divide_by_zero() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 100).unwrap();
+        assert_eq!(amount.divide(&Percent::ZERO), Err(Error::ZeroDenominator));
+    }
+
     #[test]
     fn to_fixed_decimals_exceeds_currency_decimals() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1000).unwrap();
@@ -235,6 +1197,25 @@ mod tests {
         assert!(_w.is_err(), "DECIMALS");
     }
 
+    #[test]
+    fn to_fixed_rounding_modes() {
+        let amount =
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), 1250000000000000000_i64).unwrap();
+        assert_eq!(amount.to_fixed(1, None).unwrap(), "1.2");
+        assert_eq!(
+            amount.to_fixed(1, Some(Rounding::RoundHalfEven)).unwrap(),
+            "1.2"
+        );
+        assert_eq!(
+            amount.to_fixed(1, Some(Rounding::RoundCeiling)).unwrap(),
+            "1.3"
+        );
+        assert_eq!(
+            amount.to_fixed(1, Some(Rounding::RoundHalfDown)).unwrap(),
+            "1.2"
+        );
+    }
+
     #[test]
     fn to_fixed_0_decimals() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 123456).unwrap();
@@ -265,21 +1246,204 @@ mod tests {
         assert_eq!(amount.to_significant(9, None).unwrap(), "0.001");
     }
 
+    #[test]
+    fn test_write_fixed() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 1e15 as i64).unwrap();
+        let mut buf = StackBuffer::<16>::new();
+        amount.write_fixed(&mut buf, 9, None).unwrap();
+        assert_eq!(buf.as_str(), "0.001000000");
+        let amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1000).unwrap();
+        assert_eq!(
+            amount.write_fixed(&mut buf, 3, None),
+            Err(Error::ExcessDecimalPlaces {
+                decimal_places: 3,
+                decimals: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_formatted() {
+        let usdc = token!(1, ADDRESS_ONE, 6, "USDC");
+        let amount = CurrencyAmount::from_raw_amount(usdc, 12_345_678_900_000_i64).unwrap();
+        let format = NumberFormat::new()
+            .with_thousands_separator(',')
+            .with_min_fraction_digits(2)
+            .with_max_fraction_digits(2);
+        assert_eq!(amount.to_formatted(&format).unwrap(), "12,345,678.90");
+        let mut buf = StackBuffer::<16>::new();
+        amount
+            .write_formatted(&mut buf, &format.with_compact())
+            .unwrap();
+        assert_eq!(buf.as_str(), "12.35M");
+    }
+
+    #[test]
+    fn test_mul_percent() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 1000).unwrap();
+        let fee = Percent::new(3, 1000);
+        let expected = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 3).unwrap();
+        assert_eq!(amount.clone() * &fee, expected);
+        assert_eq!(amount * fee, expected);
+    }
+
+    #[test]
+    fn test_mul_price() {
+        let usdc = token!(1, "0x0000000000000000000000000000000000000002", 6);
+        let price = Price::new(TOKEN18.clone(), usdc.clone(), 1, 5);
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 10).unwrap();
+        let quoted: CurrencyAmount<Token> = amount.clone() * &price;
+        assert_eq!(quoted, CurrencyAmount::from_raw_amount(usdc, 50).unwrap());
+        assert_eq!(amount * price, quoted);
+    }
+
+    #[test]
+    #[should_panic(expected = "currency mismatch")]
+    fn test_mul_price_currency_mismatch() {
+        let usdc = token!(1, "0x0000000000000000000000000000000000000002", 6);
+        let price = Price::new(usdc, TOKEN18.clone(), 1, 5);
+        let _ = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10).unwrap() * price;
+    }
+
+    #[test]
+    fn test_display() {
+        let amount =
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), 1234567890123456789_i64).unwrap();
+        assert_eq!(format!("{amount}"), "1.23456");
+        assert_eq!(format!("{amount:.3}"), "1.234");
+        assert_eq!(format!("{amount:#.3}"), "1.23");
+        assert_eq!(format!("{}", amount.display_with_symbol()), "1.23456");
+
+        let usdc = token!(1, ADDRESS_ONE, 6, "USDC");
+        let amount = CurrencyAmount::from_raw_amount(usdc, 1500000).unwrap();
+        assert_eq!(format!("{:.2}", amount.display_with_symbol()), "1.50 USDC");
+        let ether = CurrencyAmount::from_raw_amount(Ether::on_chain(1), 10_i64.pow(18)).unwrap();
+        assert_eq!(format!("{}", ether.display_with_symbol()), "1 ETH");
+    }
+
+    #[test]
+    fn test_display_scaled_beyond_big_int() {
+        let amount =
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, BigInt::ONE << 500_u32)
+                .unwrap();
+        assert_eq!(amount.to_string(), "3.05493E-169");
+        assert_eq!(format!("{amount:.2}"), "0.00");
+        let amount = CurrencyAmount::from_u256(TOKEN18.clone(), U256::MAX);
+        assert_eq!(
+            format!("{amount:.18}"),
+            "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+        );
+    }
+
     #[test]
     fn to_exact_does_not_throw() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1000).unwrap();
-        assert_eq!(amount.to_exact(), "1000");
+        assert_eq!(amount.to_exact(NonTerminating::Reject).unwrap(), "1000");
     }
 
     #[test]
     fn to_exact_0_decimals() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 123456).unwrap();
-        assert_eq!(amount.to_exact(), "123456");
+        assert_eq!(amount.to_exact(NonTerminating::Reject).unwrap(), "123456");
     }
 
     #[test]
     fn to_exact_18_decimals() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 123e13 as i64).unwrap();
-        assert_eq!(amount.to_exact(), "0.00123");
+        assert_eq!(amount.to_exact(NonTerminating::Reject).unwrap(), "0.00123");
+    }
+
+    #[test]
+    fn to_exact_keeps_fractional_raw_units() {
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN0.clone(), 3, 2).unwrap();
+        assert_eq!(amount.to_exact(NonTerminating::Reject).unwrap(), "1.5");
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 3, 2).unwrap();
+        assert_eq!(
+            amount.to_exact(NonTerminating::Reject).unwrap(),
+            "0.0000000000000000015"
+        );
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), -1, 8).unwrap();
+        assert_eq!(
+            amount.to_exact(NonTerminating::Reject).unwrap(),
+            "-0.000000000000000000125"
+        );
+    }
+
+    #[test]
+    fn to_exact_keeps_dust_scaled_beyond_big_int() {
+        let amount =
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, BigInt::ONE << 500_u32)
+                .unwrap();
+        // 1 / 2^500 = 5^500 / 10^500, and 5^500 has 350 digits
+        let exact = amount.to_exact(NonTerminating::Reject).unwrap();
+        assert_eq!(exact.len(), 2 + 18 + 500);
+        assert!(exact.starts_with(&format!("0.{}3054936", "0".repeat(168))));
+        assert!(exact.ends_with("898681640625"));
+        assert_eq!(amount.to_significant(6, None).unwrap(), "3.05493E-169");
+        assert_eq!(amount.to_fixed(6, None).unwrap(), "0.000000");
+        let amount =
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, BigInt::from(3) << 500_u32)
+                .unwrap();
+        let rounded = NonTerminating::Round {
+            decimal_places: 160,
+            rounding: Rounding::RoundUp,
+        };
+        assert_eq!(
+            amount.to_exact(rounded).unwrap(),
+            format!("0.{}1", "0".repeat(159))
+        );
+    }
+
+    #[test]
+    fn to_exact_non_terminating() {
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN0.clone(), 10, 3).unwrap();
+        assert_eq!(
+            amount.to_exact(NonTerminating::Reject),
+            Err(Error::NonTerminating)
+        );
+        assert_eq!(
+            amount
+                .to_exact(NonTerminating::Repeating { max_digits: 10 })
+                .unwrap(),
+            "3.(3)"
+        );
+        assert_eq!(
+            amount.to_exact(NonTerminating::Round {
+                decimal_places: 4,
+                rounding: Rounding::RoundDown,
+            }),
+            Ok("3.3333".to_string())
+        );
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, 7).unwrap();
+        assert_eq!(
+            amount.to_exact(NonTerminating::Repeating { max_digits: 20 }),
+            Err(Error::MaxDigitsExceeded(20))
+        );
+        assert_eq!(
+            amount
+                .to_exact(NonTerminating::Repeating { max_digits: 24 })
+                .unwrap(),
+            "0.000000000000000000(142857)"
+        );
+    }
+
+    #[test]
+    fn to_exact_raw_keeps_dust() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 100)
+            .unwrap()
+            .multiply(&Percent::new(1, 3))
+            .unwrap();
+        let (raw, dust) = amount.to_exact_raw();
+        assert_eq!(raw, BigInt::from(33));
+        assert_eq!(dust, Fraction::new(1, 3));
+        assert_eq!(Fraction::new(raw, 1) + dust, amount.as_fraction());
+
+        let amount = CurrencyAmount::from_fractional_amount(TOKEN18.clone(), -7, 2).unwrap();
+        let (raw, dust) = amount.to_exact_raw();
+        assert_eq!(raw, BigInt::from(-4));
+        assert_eq!(dust, Fraction::new(1, 2));
+
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 42).unwrap();
+        assert_eq!(amount.to_exact_raw(), (BigInt::from(42), Fraction::ZERO));
     }
 }
@@ -0,0 +1,731 @@
+use super::fraction::rounds_away_from_zero;
+use crate::prelude::*;
+use core::{cmp::Ordering, fmt, iter, str};
+use fastnum::{Cast, U1024};
+
+/// Largest power of ten fitting in a `u64`, used to split integers into chunks of digits
+const DIGIT_CHUNK: u64 = 10_000_000_000_000_000_000;
+
+/// Number of digits in a [`DIGIT_CHUNK`]
+const DIGIT_CHUNK_LEN: usize = 19;
+
+/// Enough whole chunks for the 155 decimal digits of any [`BigUint`]
+const INTEGER_DIGITS_CAPACITY: usize = 9 * DIGIT_CHUNK_LEN;
+
+/// Fraction digits below which values rendered with significant digits switch to scientific
+/// notation, e.g. `0.000001` but `1E-7`
+const SCIENTIFIC_LEADING_ZEROS: isize = 5;
+
+/// Exact decimal expansion of a fraction, split into its integer part, the digits preceding the
+/// repeating cycle and the repeating cycle itself.
+///
+/// ```
+/// use uniswap_sdk_core::prelude::*;
+///
+/// let expansion = Fraction::new(1, 7).to_decimal_expansion(100).unwrap();
+/// assert_eq!(expansion.repeating, "142857");
+/// assert_eq!(expansion.to_string(), "0.(142857)");
+///
+/// let expansion = Fraction::new(-11, 6).to_decimal_expansion(100).unwrap();
+/// assert_eq!(expansion.to_string(), "-1.8(3)");
+/// assert!(Fraction::new(5, 4).to_decimal_expansion(100).unwrap().is_terminating());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalExpansion {
+    /// Whether the value is negative. Always `false` for zero.
+    pub negative: bool,
+    /// Magnitude of the integer part
+    pub integer: BigUint,
+    /// Fraction digits preceding the repeating cycle
+    pub non_repeating: String,
+    /// Repeating cycle of fraction digits, empty if the expansion terminates
+    pub repeating: String,
+}
+
+impl DecimalExpansion {
+    /// Computes the decimal expansion of `numerator / denominator`.
+    ///
+    /// Returns [`Error::ZeroDenominator`] if the denominator is zero and
+    /// [`Error::MaxDigitsExceeded`] if the expansion has more than `max_digits` fraction digits.
+    #[inline]
+    pub fn new(numerator: BigInt, denominator: BigInt, max_digits: usize) -> Result<Self, Error> {
+        if denominator.is_zero() {
+            return Err(Error::ZeroDenominator);
+        }
+        let negative = !numerator.is_zero() && numerator.is_negative() != denominator.is_negative();
+        let (numerator, denominator) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+        let gcd = numerator.gcd(&denominator);
+        let (numerator, denominator) = (numerator / gcd, denominator / gcd);
+
+        // the digits before the cycle are as many as the larger power of 2 or 5 in the denominator
+        let twos = denominator.trailing_zeros();
+        let mut fives = 0;
+        let mut coprime = denominator >> twos;
+        while coprime.is_multiple_of(&BigUint::FIVE) {
+            coprime /= BigUint::FIVE;
+            fives += 1;
+        }
+        let preperiod = twos.max(fives) as usize;
+        if preperiod > max_digits {
+            return Err(Error::MaxDigitsExceeded(max_digits));
+        }
+
+        let mut division = LongDivision::new(numerator % denominator, denominator);
+        let non_repeating = (0..preperiod)
+            .map(|_| digit_char(division.next_digit()))
+            .collect();
+        let mut repeating = String::new();
+        if coprime != BigUint::ONE {
+            // past the preperiod the remainders are purely periodic
+            let start = division.remainder;
+            loop {
+                if preperiod + repeating.len() == max_digits {
+                    return Err(Error::MaxDigitsExceeded(max_digits));
+                }
+                repeating.push(digit_char(division.next_digit()));
+                if division.remainder == start {
+                    break;
+                }
+            }
+        }
+        Ok(Self {
+            negative,
+            integer: numerator / denominator,
+            non_repeating,
+            repeating,
+        })
+    }
+
+    /// Divides the value by `10^places` by moving the decimal point to the left, keeping the
+    /// expansion canonical
+    #[inline]
+    pub(crate) fn shifted(self, places: usize) -> Self {
+        let digits = IntegerDigits::new(self.integer);
+        let digits = digits.as_bytes();
+        let split = digits.len().saturating_sub(places);
+        let mut non_repeating = String::with_capacity(places + self.non_repeating.len());
+        non_repeating.extend(iter::repeat_n('0', places.saturating_sub(digits.len())));
+        non_repeating.extend(digits[split..].iter().map(|digit| *digit as char));
+        non_repeating.push_str(&self.non_repeating);
+        let mut repeating = self.repeating;
+        if repeating.is_empty() {
+            non_repeating.truncate(non_repeating.trim_end_matches('0').len());
+        }
+        // digits preceding the cycle that match its end belong to the cycle, e.g. 0.3(3) is 0.(3)
+        while let Some(last) = repeating.chars().next_back()
+            && non_repeating.ends_with(last)
+        {
+            non_repeating.pop();
+            repeating.pop();
+            repeating.insert(0, last);
+        }
+        let integer = match u32::try_from(places) {
+            Ok(places) if split > 0 => self.integer / BigUint::TEN.pow(places),
+            _ => BigUint::ZERO,
+        };
+        Self {
+            negative: self.negative,
+            integer,
+            non_repeating,
+            repeating,
+        }
+    }
+
+    /// Returns `true` if the expansion has no repeating cycle
+    #[inline]
+    #[must_use]
+    pub const fn is_terminating(&self) -> bool {
+        self.repeating.is_empty()
+    }
+}
+
+impl fmt::Display for DecimalExpansion {
+    /// Formats the expansion with the repeating cycle in parentheses, e.g. `-1.8(3)`
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.integer)?;
+        if !self.non_repeating.is_empty() || !self.repeating.is_empty() {
+            write!(f, ".{}", self.non_repeating)?;
+        }
+        if !self.repeating.is_empty() {
+            write!(f, "({})", self.repeating)?;
+        }
+        Ok(())
+    }
+}
+
+/// Schoolbook long division producing one fraction digit at a time
+struct LongDivision {
+    /// Remainder left after the digits produced so far, always less than the divisor
+    remainder: U1024,
+    divisor: U1024,
+}
+
+impl LongDivision {
+    /// Starts the division of a remainder by a larger divisor
+    #[inline]
+    fn new(remainder: BigUint, divisor: BigUint) -> Self {
+        Self {
+            remainder: remainder.cast(),
+            divisor: divisor.cast(),
+        }
+    }
+
+    /// Produces the next fraction digit
+    #[inline]
+    fn next_digit(&mut self) -> u8 {
+        // the remainder is below 2^512, so multiplying it by ten cannot overflow
+        let (digit, remainder) = (self.remainder * U1024::TEN).div_rem(self.divisor);
+        self.remainder = remainder;
+        digit.digits()[0] as u8
+    }
+}
+
+/// Converts a digit to its character
+#[inline]
+const fn digit_char(digit: u8) -> char {
+    (b'0' + digit) as char
+}
+
+/// ASCII digits of a [`BigUint`] without leading zeros, held on the stack
+struct IntegerDigits {
+    buf: [u8; INTEGER_DIGITS_CAPACITY],
+    start: usize,
+}
+
+impl IntegerDigits {
+    #[inline]
+    fn new(mut value: BigUint) -> Self {
+        let mut buf = [b'0'; INTEGER_DIGITS_CAPACITY];
+        let mut start = INTEGER_DIGITS_CAPACITY;
+        let chunk = BigUint::from_u64(DIGIT_CHUNK);
+        while !value.is_zero() {
+            let (quotient, remainder) = value.div_rem(chunk);
+            let mut remainder = remainder.digits()[0];
+            for _ in 0..DIGIT_CHUNK_LEN {
+                start -= 1;
+                buf[start] = b'0' + (remainder % 10) as u8;
+                remainder /= 10;
+            }
+            value = quotient;
+        }
+        while start < INTEGER_DIGITS_CAPACITY && buf[start] == b'0' {
+            start += 1;
+        }
+        Self { buf, start }
+    }
+
+    /// Returns the digits, empty for zero
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        &self.buf[self.start..]
+    }
+}
+
+/// Writes the decimal digits of a [`BigUint`] without building a `String`
+#[inline]
+pub(crate) fn write_big_uint(w: &mut impl fmt::Write, value: BigUint) -> fmt::Result {
+    let digits = IntegerDigits::new(value);
+    match digits.as_bytes() {
+        [] => w.write_char('0'),
+        // the buffer only holds ASCII digits
+        bytes => w.write_str(str::from_utf8(bytes).map_err(|_| fmt::Error)?),
+    }
+}
+
+/// Cursor over the decimal digits of a non-negative fraction: the digits of its integer part
+/// followed by its fraction digits. Positions count from the most significant integer digit, so
+/// negative positions are leading zeros.
+///
+/// Fraction digits are produced by long division as the position advances, so positions must be
+/// visited in increasing order until the cursor is restarted.
+struct Digits {
+    integer: IntegerDigits,
+    /// Remainder of the integer division, from which the fraction digits start
+    remainder: U1024,
+    division: LongDivision,
+    /// Number of fraction digits produced so far
+    produced: usize,
+    /// Last fraction digit produced
+    last: u8,
+}
+
+/// Outcome of rounding the digits up to some position
+struct Rounded {
+    /// Whether the kept digits are rounded away from zero
+    up: bool,
+    /// Position of the last kept digit other than nine, which absorbs the carry of rounding up.
+    /// `None` if the carry creates a new leading one.
+    last_non_nine: Option<isize>,
+    /// Position of the last non-zero kept digit after rounding, `None` if all are zero
+    last_non_zero: Option<isize>,
+}
+
+impl Rounded {
+    /// Returns the digit at `position` after rounding
+    #[inline]
+    const fn apply(&self, position: isize, digit: u8) -> u8 {
+        match self.last_non_nine {
+            _ if !self.up => digit,
+            Some(last) if position == last => digit + 1,
+            Some(last) if position < last => digit,
+            _ => 0,
+        }
+    }
+
+    /// Returns `true` if rounding carries past the first kept digit
+    #[inline]
+    const fn carries(&self) -> bool {
+        self.up && self.last_non_nine.is_none()
+    }
+}
+
+impl Digits {
+    #[inline]
+    fn new(numerator: BigUint, denominator: BigUint) -> Self {
+        let division = LongDivision::new(numerator % denominator, denominator);
+        Self {
+            integer: IntegerDigits::new(numerator / denominator),
+            remainder: division.remainder,
+            division,
+            produced: 0,
+            last: 0,
+        }
+    }
+
+    /// Number of digits of the integer part, zero if it is zero
+    #[inline]
+    fn integer_len(&self) -> isize {
+        self.integer.as_bytes().len() as isize
+    }
+
+    /// Returns the digit at `position`
+    #[inline]
+    fn digit(&mut self, position: isize) -> u8 {
+        let Ok(position) = usize::try_from(position) else {
+            return 0;
+        };
+        let integer = self.integer.as_bytes();
+        if let Some(digit) = integer.get(position) {
+            return digit - b'0';
+        }
+        let index = position - integer.len();
+        debug_assert!(index + 1 >= self.produced, "positions must not decrease");
+        while self.produced <= index {
+            self.last = self.division.next_digit();
+            self.produced += 1;
+        }
+        self.last
+    }
+
+    /// Returns `true` if all digits after `position`, the last position visited, are zero
+    #[inline]
+    fn is_zero_after(&self, position: isize) -> bool {
+        let integer = self.integer.as_bytes();
+        match usize::try_from(position + 1).unwrap_or(0) {
+            start if start <= integer.len() => {
+                integer[start..].iter().all(|digit| *digit == b'0') && self.remainder.is_zero()
+            }
+            _ => self.division.remainder.is_zero(),
+        }
+    }
+
+    /// Returns the position of the first non-zero digit, `None` if the fraction is zero
+    #[inline]
+    fn first_non_zero(&mut self) -> Option<isize> {
+        if self.integer_len() > 0 {
+            return Some(0);
+        }
+        if self.remainder.is_zero() {
+            return None;
+        }
+        // a non-zero remainder always produces a non-zero digit eventually
+        let first = (0..).find(|position| self.digit(*position) != 0);
+        self.restart();
+        first
+    }
+
+    /// Rounds the digits in `start..end` to a whole number of units of the last one
+    #[inline]
+    fn round(&mut self, start: isize, end: isize, negative: bool, rounding: Rounding) -> Rounded {
+        let (mut last_non_nine, mut last_non_zero, mut last) = (None, None, 0);
+        for position in start..end {
+            last = self.digit(position);
+            if last != 9 {
+                last_non_nine = Some(position);
+            }
+            if last != 0 {
+                last_non_zero = Some(position);
+            }
+        }
+        let next = self.digit(end);
+        let sticky = !self.is_zero_after(end);
+        let half = match next.cmp(&5) {
+            Ordering::Equal if sticky => Ordering::Greater,
+            half => half,
+        };
+        let up =
+            (next != 0 || sticky) && rounds_away_from_zero(rounding, negative, half, last % 2 == 0);
+        self.restart();
+        Rounded {
+            up,
+            last_non_nine,
+            last_non_zero: if up { last_non_nine } else { last_non_zero },
+        }
+    }
+
+    /// Rewinds the cursor to the first fraction digit
+    #[inline]
+    const fn restart(&mut self) {
+        self.division.remainder = self.remainder;
+        self.produced = 0;
+    }
+}
+
+/// Writes `numerator / denominator / 10^shift` with exactly `decimal_places` correctly rounded
+/// fraction digits
+#[inline]
+pub(crate) fn write_fixed_digits(
+    w: &mut impl fmt::Write,
+    numerator: BigInt,
+    denominator: BigInt,
+    shift: isize,
+    decimal_places: usize,
+    rounding: Rounding,
+) -> fmt::Result {
+    let negative = numerator.is_negative() != denominator.is_negative();
+    let mut digits = Digits::new(numerator.unsigned_abs(), denominator.unsigned_abs());
+    let point = digits.integer_len() - shift;
+    // keep at least the units digit, which may be a leading zero
+    let start = (point - 1).min(0);
+    let end = point + decimal_places as isize;
+    let rounded = digits.round(start, end, negative, rounding);
+    if negative && (rounded.up || rounded.last_non_zero.is_some()) {
+        w.write_char('-')?;
+    }
+    let mut leading = true;
+    if rounded.carries() {
+        w.write_char('1')?;
+        leading = false;
+    }
+    for position in start..end {
+        if position == point {
+            w.write_char('.')?;
+        }
+        let digit = rounded.apply(position, digits.digit(position));
+        if leading && digit == 0 && position < point - 1 {
+            continue;
+        }
+        leading = false;
+        w.write_char(digit_char(digit))?;
+    }
+    Ok(())
+}
+
+/// Writes `numerator / denominator / 10^shift` rounded to `significant_digits` significant
+/// digits, with trailing zeros removed.
+///
+/// Values with more than five leading fraction zeros are written in scientific notation, e.g.
+/// `3.707E-12`.
+#[inline]
+pub(crate) fn write_significant_digits(
+    w: &mut impl fmt::Write,
+    numerator: BigInt,
+    denominator: BigInt,
+    shift: isize,
+    significant_digits: usize,
+    rounding: Rounding,
+) -> fmt::Result {
+    let negative = numerator.is_negative() != denominator.is_negative();
+    let mut digits = Digits::new(numerator.unsigned_abs(), denominator.unsigned_abs());
+    let point = digits.integer_len() - shift;
+    let Some(first) = digits.first_non_zero() else {
+        return w.write_char('0');
+    };
+    let rounded = digits.round(
+        first,
+        first + significant_digits as isize,
+        negative,
+        rounding,
+    );
+    let (lead, last) = if rounded.carries() {
+        (first - 1, first - 1)
+    } else {
+        (first, rounded.last_non_zero.unwrap_or(first))
+    };
+    let mut digit = |position: isize| match position {
+        _ if position == lead && rounded.carries() => 1,
+        _ if position > last => 0,
+        _ => rounded.apply(position, digits.digit(position)),
+    };
+    if negative {
+        w.write_char('-')?;
+    }
+    // the power of ten of the leading digit
+    let exponent = point - 1 - lead;
+    if exponent < -SCIENTIFIC_LEADING_ZEROS - 1 {
+        w.write_char(digit_char(digit(lead)))?;
+        if last > lead {
+            w.write_char('.')?;
+            for position in lead + 1..=last {
+                w.write_char(digit_char(digit(position)))?;
+            }
+        }
+        return write!(w, "E{exponent}");
+    }
+    if exponent < 0 {
+        w.write_str("0.")?;
+        for _ in exponent + 1..0 {
+            w.write_char('0')?;
+        }
+    }
+    for position in lead..point.max(last + 1) {
+        if position == point && position > lead {
+            w.write_char('.')?;
+        }
+        w.write_char(digit_char(digit(position)))?;
+    }
+    Ok(())
+}
+
+/// Renders `numerator / denominator` with exactly `decimal_places` correctly rounded fraction
+/// digits
+#[inline]
+pub(crate) fn decimal_with_precision(
+    numerator: BigInt,
+    denominator: BigInt,
+    decimal_places: usize,
+    rounding: Rounding,
+) -> String {
+    let mut s = String::with_capacity(decimal_places + 2);
+    // writing into a `String` cannot fail
+    let _ = write_fixed_digits(&mut s, numerator, denominator, 0, decimal_places, rounding);
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn expand(numerator: i64, denominator: i64) -> String {
+        DecimalExpansion::new(numerator.into(), denominator.into(), 1000)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_expansion() {
+        assert_eq!(expand(1, 7), "0.(142857)");
+        assert_eq!(expand(1, 3), "0.(3)");
+        assert_eq!(expand(-11, 6), "-1.8(3)");
+        assert_eq!(expand(1, 12), "0.08(3)");
+        assert_eq!(expand(22, 7), "3.(142857)");
+        assert_eq!(expand(5, 4), "1.25");
+        assert_eq!(expand(-6, 3), "-2");
+        assert_eq!(expand(0, -5), "0");
+        assert_eq!(expand(1, 1_000_000), "0.000001");
+        assert_eq!(expand(3, -40), "-0.075");
+    }
+
+    #[test]
+    fn test_expansion_parts() {
+        let expansion = DecimalExpansion::new(BigInt::from(-25), BigInt::from(14), 100).unwrap();
+        assert_eq!(
+            expansion,
+            DecimalExpansion {
+                negative: true,
+                integer: BigUint::ONE,
+                non_repeating: "7".into(),
+                repeating: "857142".into(),
+            }
+        );
+        assert!(!expansion.is_terminating());
+    }
+
+    #[test]
+    fn test_shifted_expansion() {
+        let shifted = |numerator: i64, denominator: i64, places: usize| {
+            DecimalExpansion::new(numerator.into(), denominator.into(), 1000)
+                .unwrap()
+                .shifted(places)
+                .to_string()
+        };
+        assert_eq!(shifted(10, 3, 1), "0.(3)");
+        assert_eq!(shifted(10, 7, 1), "0.(142857)");
+        assert_eq!(shifted(-35, 3, 1), "-1.1(6)");
+        assert_eq!(shifted(12_345, 1, 2), "123.45");
+        assert_eq!(shifted(100, 1, 2), "1");
+        assert_eq!(shifted(15, 2, 3), "0.0075");
+        assert_eq!(shifted(1, 3, 2), "0.00(3)");
+        assert_eq!(shifted(7, 1, 0), "7");
+        assert_eq!(shifted(0, 1, 5), "0");
+        assert_eq!(shifted(1, 1, 200), format!("0.{}1", "0".repeat(199)));
+    }
+
+    #[test]
+    fn test_expansion_max_digits() {
+        // 1/97 has a cycle of 96 digits
+        assert_eq!(
+            DecimalExpansion::new(BigInt::ONE, BigInt::from(97), 95),
+            Err(Error::MaxDigitsExceeded(95))
+        );
+        let expansion = DecimalExpansion::new(BigInt::ONE, BigInt::from(97), 96).unwrap();
+        assert_eq!(expansion.repeating.len(), 96);
+        assert_eq!(
+            DecimalExpansion::new(BigInt::ONE, BigInt::from(1024), 9),
+            Err(Error::MaxDigitsExceeded(9))
+        );
+        assert_eq!(
+            DecimalExpansion::new(BigInt::ONE, BigInt::ZERO, 9),
+            Err(Error::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_expansion_large_denominator() {
+        let expansion =
+            DecimalExpansion::new(BigInt::ONE, BigInt::ONE << 500_u32, usize::MAX).unwrap();
+        assert!(expansion.is_terminating());
+        assert_eq!(expansion.non_repeating.len(), 500);
+        assert!(expansion.non_repeating.ends_with("625"));
+    }
+
+    #[test]
+    fn test_decimal_with_precision() {
+        let to = |n: i64, d: i64, places: usize, rounding: Rounding| {
+            decimal_with_precision(n.into(), d.into(), places, rounding)
+        };
+        assert_eq!(to(2, 3, 5, Rounding::RoundHalfUp), "0.66667");
+        assert_eq!(to(2, 3, 5, Rounding::RoundDown), "0.66666");
+        assert_eq!(to(-2, 3, 2, Rounding::RoundFloor), "-0.67");
+        assert_eq!(to(-2, 3, 2, Rounding::RoundCeiling), "-0.66");
+        assert_eq!(to(999, 1000, 2, Rounding::RoundHalfUp), "1.00");
+        assert_eq!(to(-1999, 1000, 0, Rounding::RoundHalfUp), "-2");
+        assert_eq!(to(5, 2, 0, Rounding::RoundHalfEven), "2");
+        assert_eq!(to(15, 200, 2, Rounding::RoundHalfEven), "0.08");
+        assert_eq!(to(125, 1000, 2, Rounding::RoundHalfEven), "0.12");
+        assert_eq!(to(125, 1000, 2, Rounding::RoundHalfDown), "0.12");
+        assert_eq!(to(-1, 1000, 2, Rounding::RoundHalfUp), "0.00");
+        assert_eq!(to(7, 1, 3, Rounding::RoundUp), "7.000");
+    }
+
+    #[test]
+    fn test_decimal_with_precision_beyond_big_decimal() {
+        let s = Fraction::new(1, 7).to_decimal_with_precision(300, None);
+        assert_eq!(s.len(), 302);
+        assert_eq!(s, format!("0.{}142857", "142857".repeat(49)));
+        let s = Fraction::new(1, 7).to_decimal_with_precision(299, None);
+        assert!(s.ends_with("14286"));
+    }
+
+    #[test]
+    fn test_fixed_digits() {
+        let fixed = |n: i64, d: i64, shift: isize, places: usize, rounding: Rounding| {
+            let mut s = String::new();
+            write_fixed_digits(&mut s, n.into(), d.into(), shift, places, rounding).unwrap();
+            s
+        };
+        assert_eq!(fixed(999_999, 1000, 0, 2, Rounding::RoundHalfUp), "1000.00");
+        assert_eq!(fixed(-999, 1, 3, 2, Rounding::RoundHalfUp), "-1.00");
+        assert_eq!(fixed(-1, 1000, 0, 2, Rounding::RoundFloor), "-0.01");
+        assert_eq!(fixed(-1, 1000, 0, 2, Rounding::RoundCeiling), "0.00");
+        assert_eq!(fixed(5, 100, -3, 0, Rounding::RoundDown), "50");
+        assert_eq!(fixed(12345, 1, 2, 1, Rounding::RoundHalfEven), "123.4");
+        assert_eq!(fixed(12355, 1, 3, 0, Rounding::RoundHalfEven), "12");
+        assert_eq!(fixed(1, 3, 5, 3, Rounding::RoundUp), "0.001");
+        assert_eq!(fixed(0, 7, 2, 2, Rounding::RoundUp), "0.00");
+    }
+
+    #[test]
+    fn test_significant_digits() {
+        let significant = |n: BigInt, d: BigInt, shift: isize, digits: usize| {
+            let mut s = String::new();
+            write_significant_digits(&mut s, n, d, shift, digits, Rounding::RoundHalfUp).unwrap();
+            s
+        };
+        let sig = |n: i64, d: i64, digits: usize| significant(n.into(), d.into(), 0, digits);
+        assert_eq!(sig(2, 3, 4), "0.6667");
+        assert_eq!(sig(-2, 3, 1), "-0.7");
+        assert_eq!(sig(123_456_789, 1, 3), "123000000");
+        assert_eq!(sig(99_999, 1000, 3), "100");
+        assert_eq!(sig(99_999, 100_000, 3), "1");
+        assert_eq!(sig(1, 1_000_000, 3), "0.000001");
+        assert_eq!(sig(1, 10_000_000, 3), "1E-7");
+        assert_eq!(sig(3707, 10_i64.pow(15), 4), "3.707E-12");
+        assert_eq!(sig(9_999_999, 10_i64.pow(14), 2), "1E-7");
+        assert_eq!(sig(0, 5, 3), "0");
+        assert_eq!(sig(15, 10, 6), "1.5");
+        // the digits of a raw amount scaled by its decimals
+        assert_eq!(
+            significant(BigInt::ONE, BigInt::ONE << 500_u32, 18, 3),
+            "3.05E-169"
+        );
+        assert_eq!(significant(BigInt::from(25), BigInt::ONE, -2, 3), "2500");
+    }
+
+    #[cfg(feature = "std")]
+    mod allocations {
+        use super::*;
+        use crate::utils::StackBuffer;
+        use core::fmt::Write;
+        use std::{
+            alloc::{GlobalAlloc, Layout, System},
+            cell::Cell,
+        };
+
+        std::thread_local! {
+            static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        /// Counts the allocations of the current thread
+        struct CountingAllocator;
+
+        unsafe impl GlobalAlloc for CountingAllocator {
+            unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+                let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+                unsafe { System.alloc(layout) }
+            }
+
+            unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+                unsafe { System.dealloc(ptr, layout) }
+            }
+        }
+
+        #[global_allocator]
+        static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+        fn allocations(f: impl FnOnce()) -> usize {
+            let before = ALLOCATIONS.with(Cell::get);
+            f();
+            ALLOCATIONS.with(Cell::get) - before
+        }
+
+        #[test]
+        fn formatting_into_a_stack_buffer_does_not_allocate() {
+            let fraction = Fraction::new(-123_456_789, BigInt::TEN.pow(40));
+            let large = Fraction::new(MAX_UINT256, 7);
+            let format = NumberFormat::new()
+                .with_thousands_separator(',')
+                .with_subscript_zeros(4);
+            let mut buf = StackBuffer::<1024>::new();
+            assert_eq!(allocations(|| drop(String::from("counted"))), 1);
+            assert_eq!(
+                allocations(|| {
+                    fraction.write_significant(&mut buf, 6, None).unwrap();
+                    large.write_significant(&mut buf, 30, None).unwrap();
+                    fraction.write_fixed(&mut buf, 45, None).unwrap();
+                    large.write_fixed(&mut buf, 10, None).unwrap();
+                    format.write(&mut buf, &fraction).unwrap();
+                    format.write(&mut buf, &large).unwrap();
+                    write!(buf, "{fraction} {large:.2}").unwrap();
+                }),
+                0
+            );
+            assert!(buf.starts_with("-1.23457E-32"));
+        }
+    }
+}
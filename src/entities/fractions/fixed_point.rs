@@ -0,0 +1,326 @@
+use super::fraction::rounds_away_from_zero;
+use crate::prelude::*;
+use fastnum::{Cast, I1024, TryCast};
+
+/// Largest number of fraction bits accepted by [`Fraction::from_q`] and [`FractionBase::to_q`]
+pub const MAX_Q_FRACTIONAL_BITS: u32 = 256;
+
+impl Fraction {
+    /// Lifts an unsigned fixed-point number with `frac_bits` fraction bits, such as
+    /// `sqrtPriceX96`, into the exact rational `value / 2^frac_bits`.
+    ///
+    /// The result is in reduced canonical form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FractionalBitsExceeded`] if `frac_bits` exceeds
+    /// [`MAX_Q_FRACTIONAL_BITS`].
+    #[inline]
+    pub fn from_q(value: U256, frac_bits: u32) -> Result<Self, Error> {
+        if frac_bits > MAX_Q_FRACTIONAL_BITS {
+            return Err(Error::FractionalBitsExceeded {
+                bits: frac_bits,
+                max: MAX_Q_FRACTIONAL_BITS,
+            });
+        }
+        Ok(Self::from_q_unchecked(value, frac_bits))
+    }
+
+    /// Lifts a fixed-point number whose fraction bits are known not to exceed
+    /// [`MAX_Q_FRACTIONAL_BITS`]
+    #[inline]
+    fn from_q_unchecked(value: U256, frac_bits: u32) -> Self {
+        let value = value.to_big_int();
+        // dividing out the common factors of two keeps the result reduced
+        let shift = if value.is_zero() {
+            frac_bits
+        } else {
+            value.trailing_zeros().min(frac_bits)
+        };
+        Self {
+            numerator: value >> shift,
+            denominator: BigInt::ONE << (frac_bits - shift),
+            meta: (),
+        }
+    }
+}
+
+/// Encodes `numerator / denominator` as an unsigned fixed-point number with `frac_bits` fraction
+/// bits
+#[inline]
+pub(crate) fn to_q_parts(
+    numerator: BigInt,
+    denominator: BigInt,
+    frac_bits: u32,
+    rounding: Rounding,
+) -> Result<U256, Error> {
+    if frac_bits > MAX_Q_FRACTIONAL_BITS {
+        return Err(Error::FractionalBitsExceeded {
+            bits: frac_bits,
+            max: MAX_Q_FRACTIONAL_BITS,
+        });
+    }
+    let negative = !numerator.is_zero() && numerator.is_negative() != denominator.is_negative();
+    let value = move || Fraction::new(numerator, denominator);
+    // |numerator| < 2^511 and frac_bits <= 256, so the shifted value fits in 1024 bits
+    let numerator: I1024 = numerator.unsigned_abs().cast();
+    let numerator = numerator << frac_bits;
+    let denominator: I1024 = denominator.unsigned_abs().cast();
+    let (quotient, remainder) = numerator.div_rem(denominator);
+    let quotient = if !remainder.is_zero()
+        && rounds_away_from_zero(
+            rounding,
+            negative,
+            (remainder << 1_u32).cmp(&denominator),
+            quotient.is_even(),
+        ) {
+        quotient + I1024::ONE
+    } else {
+        quotient
+    };
+    // negative values are rejected even when they round to zero
+    if negative {
+        return Err(Error::negative(value()));
+    }
+    let max: I1024 = MAX_UINT256.cast();
+    if quotient > max {
+        return Err(Error::uint_overflow(value()));
+    }
+    let quotient: BigInt = quotient
+        .try_cast()
+        .map_err(|_| Error::uint_overflow(value()))?;
+    Ok(U256::from_big_int(quotient))
+}
+
+macro_rules! define_q_format {
+    ($(#[$attr:meta])* $name:ident, $integer_bits:literal, $frac_bits:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name(pub U256);
+
+        impl $name {
+            /// Number of integer bits
+            pub const INTEGER_BITS: u32 = $integer_bits;
+
+            /// Number of fraction bits
+            pub const FRACTIONAL_BITS: u32 = $frac_bits;
+
+            /// Wraps a raw on-chain value
+            #[inline]
+            #[must_use]
+            pub const fn new(raw: U256) -> Self {
+                Self(raw)
+            }
+
+            /// Returns the raw on-chain value
+            #[inline]
+            #[must_use]
+            pub const fn raw(self) -> U256 {
+                self.0
+            }
+
+            /// Returns the exact value as a [`Fraction`]
+            #[inline]
+            #[must_use]
+            pub fn to_fraction(self) -> Fraction {
+                Fraction::from_q_unchecked(self.0, Self::FRACTIONAL_BITS)
+            }
+
+            /// Encodes a fraction, rounding the last fraction bit with the given rounding
+            /// strategy.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::Negative`] if the fraction is negative and
+            #[doc = concat!("[`Error::UintOverflow`] if it does not fit in ", $integer_bits, " integer bits.")]
+            #[inline]
+            pub fn from_fraction<M: Clone>(
+                fraction: &impl FractionBase<M>,
+                rounding: Rounding,
+            ) -> Result<Self, Error> {
+                let raw = fraction.to_q(Self::FRACTIONAL_BITS, rounding)?;
+                if raw.bit_len() > (Self::INTEGER_BITS + Self::FRACTIONAL_BITS) as usize {
+                    return Err(Error::uint_overflow(fraction.as_fraction()));
+                }
+                Ok(Self(raw))
+            }
+        }
+
+        impl From<$name> for Fraction {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.to_fraction()
+            }
+        }
+    };
+}
+
+define_q_format!(
+    /// Unsigned Q64.96 fixed-point number, the encoding of `sqrtPriceX96`
+    ///
+    /// ```
+    /// use uniswap_sdk_core::prelude::*;
+    ///
+    /// // sqrtPriceX96 of a price of 1
+    /// let sqrt_price = Q64x96::new(U256::from(1_u128 << 96));
+    /// assert_eq!(sqrt_price.to_fraction(), Fraction::ONE);
+    ///
+    /// let sqrt_price = Q64x96::from_fraction(&Fraction::new(3, 2), Rounding::RoundDown).unwrap();
+    /// assert_eq!(sqrt_price.raw(), U256::from(3_u128 << 95));
+    /// ```
+    Q64x96,
+    64,
+    96
+);
+
+define_q_format!(
+    /// Unsigned Q128.128 fixed-point number, the encoding of `feeGrowthGlobalX128` and other
+    /// `X128` values
+    ///
+    /// ```
+    /// use uniswap_sdk_core::prelude::*;
+    ///
+    /// let fee_growth = Q128x128::new(U256::from(1_u8) << 127);
+    /// assert_eq!(fee_growth.to_fraction(), Fraction::new(1, 2));
+    /// ```
+    Q128x128,
+    128,
+    128
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+
+    #[test]
+    fn test_from_q() {
+        let q96 = U256::from(1_u8) << 96;
+        assert_eq!(Fraction::from_q(q96, 96).unwrap(), Fraction::ONE);
+        let f = Fraction::from_q(U256::from(6_u8), 3).unwrap();
+        assert_eq!(
+            (f.numerator, f.denominator),
+            (BigInt::from(3), BigInt::from(4))
+        );
+        let f = Fraction::from_q(U256::ZERO, 96).unwrap();
+        assert_eq!((f.numerator, f.denominator), (BigInt::ZERO, BigInt::ONE));
+        let f = Fraction::from_q(U256::MAX, 256).unwrap();
+        assert_eq!(f.numerator, MAX_UINT256);
+        assert_eq!(f.denominator, BigInt::ONE << 256_u32);
+    }
+
+    #[test]
+    fn test_from_q_too_many_bits() {
+        assert_eq!(
+            Fraction::from_q(U256::ONE, 257),
+            Err(Error::FractionalBitsExceeded {
+                bits: 257,
+                max: 256
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_q_rounding() {
+        let third = Fraction::new(1, 3);
+        // 2^4 / 3 = 5.33...
+        assert_eq!(third.to_q(4, Rounding::RoundDown), Ok(U256::from(5_u8)));
+        assert_eq!(third.to_q(4, Rounding::RoundUp), Ok(U256::from(6_u8)));
+        assert_eq!(third.to_q(4, Rounding::RoundHalfUp), Ok(U256::from(5_u8)));
+        // 2^2 * 5/8 = 2.5
+        let tie = Fraction::new(5, 8);
+        assert_eq!(tie.to_q(2, Rounding::RoundHalfEven), Ok(U256::from(2_u8)));
+        assert_eq!(tie.to_q(2, Rounding::RoundHalfUp), Ok(U256::from(3_u8)));
+    }
+
+    #[test]
+    fn test_to_q_errors() {
+        assert_eq!(
+            Fraction::new(-1, 3).to_q(96, Rounding::RoundDown),
+            Err(Error::negative(Fraction::new(-1, 3)))
+        );
+        // even if it rounds to zero
+        assert_eq!(
+            Fraction::new(-1, 3).to_q(0, Rounding::RoundDown),
+            Err(Error::negative(Fraction::new(-1, 3)))
+        );
+        assert_eq!(
+            Fraction::new(0, 3).to_q(96, Rounding::RoundDown),
+            Ok(U256::ZERO)
+        );
+        assert_eq!(
+            Fraction::new(1, 1).to_q(256, Rounding::RoundDown),
+            Err(Error::uint_overflow(Fraction::ONE))
+        );
+        assert_eq!(
+            Fraction::new(1, 1).to_q(257, Rounding::RoundDown),
+            Err(Error::FractionalBitsExceeded {
+                bits: 257,
+                max: MAX_Q_FRACTIONAL_BITS
+            })
+        );
+        assert_eq!(
+            Fraction::new(MAX_UINT256, 1).to_q(0, Rounding::RoundDown),
+            Ok(U256::MAX)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for raw in [
+            U256::ZERO,
+            U256::ONE,
+            U256::from(79228162514264337593543950336_u128),
+            U256::MAX,
+        ] {
+            let fraction = Fraction::from_q(raw, 96).unwrap();
+            assert_eq!(fraction.to_q(96, Rounding::RoundDown), Ok(raw));
+            assert_eq!(
+                Q128x128::new(raw)
+                    .to_fraction()
+                    .to_q(128, Rounding::RoundUp),
+                Ok(raw)
+            );
+        }
+    }
+
+    #[test]
+    fn test_q64x96_range() {
+        let max = Fraction::new(BigInt::ONE << 64_u32, 1);
+        assert_eq!(
+            Q64x96::from_fraction(&max, Rounding::RoundDown),
+            Err(Error::uint_overflow(max.clone()))
+        );
+        let below_max = max - Fraction::from_q(U256::ONE, 96).unwrap();
+        let q = Q64x96::from_fraction(&below_max, Rounding::RoundDown).unwrap();
+        assert_eq!(q.raw(), (U256::ONE << 160) - U256::ONE);
+        assert_eq!(Fraction::from(q), below_max);
+    }
+
+    #[test]
+    fn test_price_from_sqrt_price_x96() {
+        // sqrtPriceX96 of a pool at a price of 4, squared back into the price
+        let sqrt_price = Q64x96::from_fraction(
+            &Fraction::new(4, 1).sqrt(0, Rounding::RoundDown).unwrap(),
+            Rounding::RoundDown,
+        )
+        .unwrap();
+        assert_eq!(sqrt_price.raw(), U256::from(2_u8) << 96);
+        assert_eq!(
+            sqrt_price.to_fraction().pow(2).unwrap(),
+            Fraction::new(4, 1)
+        );
+        let price = Price::new(
+            token!(1, "0x0000000000000000000000000000000000000001", 18),
+            token!(1, "0x0000000000000000000000000000000000000002", 18),
+            1,
+            4,
+        );
+        assert_eq!(
+            Q64x96::from_fraction(&price, Rounding::RoundDown)
+                .unwrap()
+                .raw(),
+            U256::from(4_u8) << 96
+        );
+    }
+}
@@ -1,35 +1,138 @@
+use super::{
+    decimal_expansion::{decimal_with_precision, write_fixed_digits, write_significant_digits},
+    fixed_point::to_q_parts,
+    math::{log_parts, nth_root_parts, pow_parts},
+};
 use crate::prelude::*;
-use alloc::string::ToString;
 use core::{
     cmp::Ordering,
+    fmt,
     hash::{Hash, Hasher},
-    ops::{Add, Div, Mul, Sub},
+    iter::{Product, Sum},
+    mem,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 use derive_more::Deref;
-use fastnum::{Cast, I1024, TryCast};
+use fastnum::{Cast, I256, I1024, TryCast};
 use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Signed, ToPrimitive, Zero};
 
 /// Struct representing a fraction with metadata
+///
+/// The numerator and denominator are [`BigInt`]s by default. Any other [`FractionInt`], such as
+/// [`I256`], can be used for faster arithmetic on values with a known bound; the
+/// [`FractionBase`] API is available for the default [`BigInt`] backing.
 #[derive(Clone, Debug, Deref)]
-pub struct FractionLike<M> {
-    pub numerator: BigInt,
-    pub denominator: BigInt,
+pub struct FractionLike<M, I = BigInt> {
+    pub numerator: I,
+    pub denominator: I,
     /// Metadata associated with the fraction
     #[deref]
     pub meta: M,
 }
 
-impl<M: Default> Default for FractionLike<M> {
+impl<M: Default, I: FractionInt> Default for FractionLike<M, I> {
     #[inline]
     fn default() -> Self {
         Self {
-            numerator: BigInt::ZERO,
-            denominator: BigInt::ONE,
+            numerator: I::zero(),
+            denominator: I::one(),
             meta: M::default(),
         }
     }
 }
 
+impl<M, I: FractionInt> FractionLike<M, I> {
+    /// Creates a fraction backed by any [`FractionInt`], normalizing a negative denominator.
+    ///
+    /// Returns [`Error::ZeroDenominator`] if the denominator is zero.
+    #[inline]
+    pub fn try_from_parts(numerator: I, denominator: I, meta: M) -> Result<Self, Error> {
+        if denominator.is_zero() {
+            return Err(Error::ZeroDenominator);
+        }
+        let (numerator, denominator) = normalize_sign(numerator, denominator)?;
+        Ok(Self {
+            numerator,
+            denominator,
+            meta,
+        })
+    }
+
+    /// Converts the fraction to another backing integer type, e.g. from [`BigInt`] to
+    /// [`I256`] or back.
+    ///
+    /// Returns [`Error::Overflow`] if the numerator or denominator does not fit in `J`.
+    #[inline]
+    pub fn try_cast<J: FractionInt>(self) -> Result<FractionLike<M, J>, Error> {
+        let convert = |value: &I| {
+            value
+                .to_big_int()
+                .ok_or(Error::Overflow(BigInt::BITS))
+                .and_then(|value| J::from_big_int(value).ok_or(Error::Overflow(J::BITS)))
+        };
+        Ok(FractionLike {
+            numerator: convert(&self.numerator)?,
+            denominator: convert(&self.denominator)?,
+            meta: self.meta,
+        })
+    }
+}
+
+impl<M: Clone, I: FractionInt> FractionLike<M, I> {
+    /// Applies a checked operation to the parts of two fractions, keeping the metadata of `self`
+    #[inline]
+    fn checked_op(
+        &self,
+        other: &Self,
+        parts: impl FnOnce((I, I), (I, I)) -> Result<(I, I), Error>,
+    ) -> Result<Self, Error> {
+        let (numerator, denominator) = parts(
+            (self.numerator.clone(), self.denominator.clone()),
+            (other.numerator.clone(), other.denominator.clone()),
+        )?;
+        Ok(Self {
+            numerator,
+            denominator,
+            meta: self.meta.clone(),
+        })
+    }
+
+    /// Adds another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::Overflow`] if the reduced result does not fit in `I`.
+    #[inline]
+    pub fn checked_add(&self, other: &Self) -> Result<Self, Error> {
+        self.checked_op(other, |a, b| add_sub_parts(a, b, false))
+    }
+
+    /// Subtracts another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::Overflow`] if the reduced result does not fit in `I`.
+    #[inline]
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, Error> {
+        self.checked_op(other, |a, b| add_sub_parts(a, b, true))
+    }
+
+    /// Multiplies by another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::Overflow`] if the reduced result does not fit in `I`.
+    #[inline]
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, Error> {
+        self.checked_op(other, mul_parts)
+    }
+
+    /// Divides by another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::ZeroDenominator`] if `other` is zero and [`Error::Overflow`] if the
+    /// reduced result does not fit in `I`.
+    #[inline]
+    pub fn checked_div(&self, other: &Self) -> Result<Self, Error> {
+        self.checked_op(other, div_parts)
+    }
+}
+
 /// Type alias for a simple Fraction without metadata
 pub type Fraction = FractionLike<()>;
 
@@ -59,21 +162,596 @@ impl Fraction {
     ///
     /// # Panics
     ///
-    /// This function will panic if the denominator is zero.
+    /// This function will panic if the denominator is zero, or if it is negative and normalizing
+    /// the signs overflows, e.g. for [`BigInt::MIN`].
     #[inline]
     pub fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>) -> Self {
         FractionBase::new(numerator, denominator, ())
     }
+
+    /// Creates a new `Fraction` instance, returning [`Error::ZeroDenominator`] or
+    /// [`Error::Overflow`] instead of panicking where `new` would.
+    #[inline]
+    pub fn try_new(
+        numerator: impl Into<BigInt>,
+        denominator: impl Into<BigInt>,
+    ) -> Result<Self, Error> {
+        FractionBase::try_new(numerator, denominator, ())
+    }
+
+    /// Creates a [`Fraction`] holding the exact binary value of a finite `f64`.
+    ///
+    /// See [`FractionBase::from_f64`].
+    #[inline]
+    pub fn from_f64(value: f64) -> Result<Self, Error> {
+        FractionBase::from_f64(value, ())
+    }
+
+    /// Creates the closest [`Fraction`] to a finite `f64` whose denominator is at most
+    /// `max_denominator`.
+    ///
+    /// See [`FractionBase::approximate_f64`].
+    #[inline]
+    pub fn approximate_f64(value: f64, max_denominator: impl Into<BigInt>) -> Result<Self, Error> {
+        FractionBase::approximate_f64(value, max_denominator, ())
+    }
+
+    /// Parses a [`Fraction`] from a decimal or rational string without any floating point
+    /// round-trip.
+    ///
+    /// Accepts an optional sign followed by a decimal number with an optional exponent, e.g.
+    /// `"123.456"`, `"-0.5"` or `"1e-18"`, or two such numbers separated by `/`, e.g. `"3/7"`.
+    /// The result is in reduced canonical form.
+    ///
+    /// Returns [`Error::InvalidNumber`] if the string is malformed, [`Error::ZeroDenominator`] if
+    /// the denominator of a rational string is zero and [`Error::Overflow`] if the value does not
+    /// fit in [`BigInt`].
+    #[inline]
+    pub fn from_decimal_str(s: &str) -> Result<Self, Error> {
+        let s = s.trim();
+        match s.split_once('/') {
+            Some((numerator, denominator)) => parse_decimal(numerator.trim())
+                .and_then(|n| n.checked_div(&parse_decimal(denominator.trim())?))
+                .map_err(|error| invalid_input(error, s)),
+            None => parse_decimal(s),
+        }
+    }
+}
+
+impl fmt::Display for Fraction {
+    /// Formats the fraction as a decimal.
+    ///
+    /// `{:.N}` renders `N` fixed decimal places, `{:#.N}` renders `N` significant digits and `{}`
+    /// renders [`DISPLAY_SIGNIFICANT_DIGITS`] significant digits, all rounding half up.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_decimal(f, self.numerator, self.denominator, 0, Rounding::default())
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = Error;
+
+    /// Parses a [`Fraction`] using [`Fraction::from_decimal_str`]
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_decimal_str(s)
+    }
+}
+
+/// Reports the whole input in an [`Error::InvalidNumber`] raised while parsing a part of it
+#[inline]
+pub(crate) fn invalid_input(error: Error, input: &str) -> Error {
+    match error {
+        Error::InvalidNumber(_) => Error::InvalidNumber(input.into()),
+        error => error,
+    }
 }
 
-/// Function to convert the custom Rounding enum to [`RoundingMode`]
+/// Parses a string of ASCII digits into a [`BigInt`]
 #[inline]
-const fn to_rounding_strategy(rounding: Rounding) -> RoundingMode {
-    match rounding {
-        Rounding::RoundDown => RoundingMode::Down,
-        Rounding::RoundHalfUp => RoundingMode::HalfUp,
-        Rounding::RoundUp => RoundingMode::Up,
+fn parse_digits(digits: &str) -> Result<BigInt, Error> {
+    if digits.is_empty() {
+        return Ok(BigInt::ZERO);
     }
+    BigInt::from_str_radix(digits, 10).map_err(|_| Error::Overflow(BigInt::BITS))
+}
+
+/// Parses a signed decimal number with an optional exponent into a reduced [`Fraction`]
+#[inline]
+pub(crate) fn parse_decimal(input: &str) -> Result<Fraction, Error> {
+    let invalid = || Error::InvalidNumber(input.into());
+    let (negative, s) = match input.as_bytes().first() {
+        Some(b'-') => (true, &input[1..]),
+        Some(b'+') => (false, &input[1..]),
+        _ => (false, input),
+    };
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+    let (integer, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty() && fractional.is_empty() || !is_digits(integer) || !is_digits(fractional)
+    {
+        return Err(invalid());
+    }
+    let exponent: i64 = match exponent {
+        Some(exponent) => {
+            let (sign, digits) = match exponent.as_bytes().first() {
+                Some(b'-') => (-1, &exponent[1..]),
+                Some(b'+') => (1, &exponent[1..]),
+                _ => (1, exponent),
+            };
+            if digits.is_empty() || !is_digits(digits) {
+                return Err(invalid());
+            }
+            sign * digits
+                .parse::<i64>()
+                .map_err(|_| Error::Overflow(i64::BITS))?
+        }
+        None => 0,
+    };
+    // zeros that do not change the value are folded into the exponent, so that e.g. `0.1` followed
+    // by hundreds of zeros does not overflow
+    let integer = integer.trim_start_matches('0');
+    let fractional = fractional.trim_end_matches('0');
+    let (integer, exponent) = if fractional.is_empty() {
+        let significant = integer.trim_end_matches('0');
+        let zeros = (integer.len() - significant.len()) as i64;
+        (
+            significant,
+            exponent
+                .checked_add(zeros)
+                .ok_or(Error::Overflow(i64::BITS))?,
+        )
+    } else {
+        (integer, exponent)
+    };
+    let integer = parse_digits(integer)?;
+    let fraction_digits = fractional.len() as i64;
+    let fractional = parse_digits(fractional)?;
+    let scale = |exp: i64| -> Result<BigInt, Error> {
+        u32::try_from(exp)
+            .ok()
+            .and_then(|exp| BigInt::TEN.checked_pow(exp))
+            .ok_or(Error::Overflow(BigInt::BITS))
+    };
+    let mut numerator = if integer.is_zero() {
+        fractional
+    } else {
+        integer
+            .checked_mul(scale(fraction_digits)?)
+            .and_then(|n| n.checked_add(fractional))
+            .ok_or(Error::Overflow(BigInt::BITS))?
+    };
+    if negative {
+        numerator = -numerator;
+    }
+    // value = numerator * 10^(exponent - fraction_digits)
+    let shift = exponent
+        .checked_sub(fraction_digits)
+        .ok_or(Error::Overflow(BigInt::BITS))?;
+    let fraction = if numerator.is_zero() {
+        Fraction::ZERO
+    } else if shift >= 0 {
+        Fraction::new(
+            numerator
+                .checked_mul(scale(shift)?)
+                .ok_or(Error::Overflow(BigInt::BITS))?,
+            1,
+        )
+    } else {
+        Fraction::new(
+            numerator,
+            scale(shift.checked_neg().ok_or(Error::Overflow(i64::BITS))?)?,
+        )
+    };
+    Ok(fraction.reduced())
+}
+
+/// The number of significant digits used by the [`Display`](fmt::Display) implementations of the
+/// fraction types when no precision is given
+pub const DISPLAY_SIGNIFICANT_DIGITS: u8 = 6;
+
+/// Writes `numerator / denominator / 10^shift` honoring the flags of the formatter.
+///
+/// `{:.N}` renders `N` fixed decimal places, `{:#.N}` renders `N` significant digits and `{}`
+/// renders [`DISPLAY_SIGNIFICANT_DIGITS`] significant digits.
+#[inline]
+pub(crate) fn fmt_decimal(
+    f: &mut fmt::Formatter<'_>,
+    numerator: BigInt,
+    denominator: BigInt,
+    shift: isize,
+    rounding: Rounding,
+) -> fmt::Result {
+    let precision = f.precision().map(|p| p.min(u8::MAX as usize));
+    match precision {
+        Some(decimal_places) if !f.alternate() => {
+            write_fixed_digits(f, numerator, denominator, shift, decimal_places, rounding)
+        }
+        _ => write_significant_digits(
+            f,
+            numerator,
+            denominator,
+            shift,
+            precision
+                .unwrap_or(DISPLAY_SIGNIFICANT_DIGITS as usize)
+                .max(1),
+            rounding,
+        ),
+    }
+}
+
+/// Decides whether an inexact result truncated towards zero must be rounded away from zero.
+///
+/// `half` compares the discarded remainder with one half of the last kept unit and `even` tells
+/// whether the truncated result is even.
+#[inline]
+pub(crate) const fn rounds_away_from_zero(
+    rounding: Rounding,
+    negative: bool,
+    half: Ordering,
+    even: bool,
+) -> bool {
+    match (rounding, half) {
+        (Rounding::RoundDown, _) => false,
+        (Rounding::RoundUp, _) => true,
+        (Rounding::RoundCeiling, _) => !negative,
+        (Rounding::RoundFloor, _) => negative,
+        (_, Ordering::Less) => false,
+        (_, Ordering::Greater) => true,
+        (Rounding::RoundHalfUp, Ordering::Equal) => true,
+        (Rounding::RoundHalfDown, Ordering::Equal) => false,
+        (Rounding::RoundHalfEven, Ordering::Equal) => !even,
+    }
+}
+
+/// Narrows a wide intermediate result back to [`BigInt`]
+#[inline]
+fn narrow(value: I1024) -> Result<BigInt, Error> {
+    TryCast::<BigInt>::try_cast(value).map_err(|_| Error::Overflow(BigInt::BITS))
+}
+
+/// Narrows a wide intermediate result back to `I`
+#[inline]
+fn narrow_wide<I: FractionInt>(value: I::Wide) -> Result<I, Error> {
+    I::narrow(value).ok_or(Error::Overflow(I::BITS))
+}
+
+/// Reduces a wide numerator and denominator by their gcd and narrows them back to `I`
+#[inline]
+fn reduce_wide<I: FractionInt>(numerator: I::Wide, denominator: I::Wide) -> Result<(I, I), Error> {
+    if denominator.is_zero() {
+        return Err(Error::ZeroDenominator);
+    }
+    let mut gcd = numerator.gcd(&denominator);
+    if denominator.is_negative() {
+        gcd = -gcd;
+    }
+    Ok((
+        narrow_wide(numerator / gcd.clone())?,
+        narrow_wide(denominator / gcd)?,
+    ))
+}
+
+/// Negates a value, returning [`Error::Overflow`] if the negation does not fit in `I`
+#[inline]
+fn checked_negate<I: FractionInt>(value: &I) -> Result<I, Error> {
+    narrow_wide(-value.widen())
+}
+
+/// Flips the signs of a numerator and denominator pair so that the denominator is positive
+#[inline]
+fn normalize_sign<I: FractionInt>(numerator: I, denominator: I) -> Result<(I, I), Error> {
+    if denominator < I::zero() {
+        Ok((checked_negate(&numerator)?, checked_negate(&denominator)?))
+    } else {
+        Ok((numerator, denominator))
+    }
+}
+
+/// Native integer used by the small operand fast paths.
+///
+/// Operands are accepted only if they fit in half the width of `Self`, so cross products never
+/// overflow and sums of two cross products only overflow at the extreme values, which the checked
+/// operations hand back to the wide path.
+trait SmallInt:
+    Copy + Ord + Integer + Signed + CheckedAdd + CheckedSub + CheckedMul + ToPrimitive
+{
+    /// Converts an operand, returning `None` if it does not fit in half the width of `Self`
+    fn from_operand<I: FractionInt>(value: &I) -> Option<Self>;
+
+    /// Converts a result back to `I`, returning `None` if it does not fit
+    fn into_int<I: FractionInt>(self) -> Option<I>;
+}
+
+impl SmallInt for i128 {
+    #[inline]
+    fn from_operand<I: FractionInt>(value: &I) -> Option<Self> {
+        value.to_i64().map(Self::from)
+    }
+
+    #[inline]
+    fn into_int<I: FractionInt>(self) -> Option<I> {
+        I::from_i128(self)
+    }
+}
+
+impl SmallInt for I256 {
+    #[inline]
+    fn from_operand<I: FractionInt>(value: &I) -> Option<Self> {
+        value.to_i128().and_then(<Self as FromPrimitive>::from_i128)
+    }
+
+    #[inline]
+    fn into_int<I: FractionInt>(self) -> Option<I> {
+        match ToPrimitive::to_i128(&self) {
+            Some(value) => I::from_i128(value),
+            None => I::from_big_int(self.cast()),
+        }
+    }
+}
+
+/// Converts the parts of two fractions to a native integer if all of them are small enough
+#[inline]
+fn small_operands<T: SmallInt, I: FractionInt>(
+    (a_num, a_den): &(I, I),
+    (b_num, b_den): &(I, I),
+) -> Option<[T; 4]> {
+    Some([
+        T::from_operand(a_num)?,
+        T::from_operand(a_den)?,
+        T::from_operand(b_num)?,
+        T::from_operand(b_den)?,
+    ])
+}
+
+/// Reduces a native numerator and denominator pair and converts it back to `I`.
+///
+/// Returns `None` for a zero denominator or a result that does not fit in `I`, leaving the error
+/// to the wide path.
+#[inline]
+fn reduce_small<T: SmallInt, I: FractionInt>(numerator: T, denominator: T) -> Option<(I, I)> {
+    if denominator.is_zero() {
+        return None;
+    }
+    let (numerator, denominator) = if denominator.is_one() {
+        (numerator, denominator)
+    } else {
+        let mut gcd = numerator.gcd(&denominator);
+        if denominator.is_negative() {
+            gcd = -gcd;
+        }
+        (numerator / gcd, denominator / gcd)
+    };
+    Some((numerator.into_int()?, denominator.into_int()?))
+}
+
+/// Computes `a ± b` in a native integer, returning `None` if the operands are too large
+#[inline]
+fn add_sub_small<T: SmallInt, I: FractionInt>(
+    a: &(I, I),
+    b: &(I, I),
+    subtract: bool,
+) -> Option<(I, I)> {
+    let [a_num, a_den, b_num, b_den] = small_operands::<T, I>(a, b)?;
+    let (left, right, denominator) = if a_den == b_den {
+        (a_num, b_num, a_den)
+    } else {
+        (
+            a_num.checked_mul(&b_den)?,
+            b_num.checked_mul(&a_den)?,
+            a_den.checked_mul(&b_den)?,
+        )
+    };
+    let numerator = if subtract {
+        left.checked_sub(&right)?
+    } else {
+        left.checked_add(&right)?
+    };
+    reduce_small(numerator, denominator)
+}
+
+/// Computes `(a_num * b_num) / (a_den * b_den)` in a native integer, returning `None` if the
+/// operands are too large
+#[inline]
+fn mul_small<T: SmallInt, I: FractionInt>(a: &(I, I), b: &(I, I)) -> Option<(I, I)> {
+    let [a_num, a_den, b_num, b_den] = small_operands::<T, I>(a, b)?;
+    reduce_small(a_num.checked_mul(&b_num)?, a_den.checked_mul(&b_den)?)
+}
+
+/// Compares `a_num / a_den` with `b_num / b_den` in a native integer, returning `None` if the
+/// operands are too large
+#[inline]
+fn cmp_small<T: SmallInt, I: FractionInt>(a: &(I, I), b: &(I, I)) -> Option<Ordering> {
+    let [a_num, a_den, b_num, b_den] = small_operands::<T, I>(a, b)?;
+    let left = a_num.checked_mul(&b_den)?;
+    let right = b_num.checked_mul(&a_den)?;
+    // cross multiplication flips the ordering if exactly one denominator is negative
+    if a_den.is_negative() == b_den.is_negative() {
+        Some(left.cmp(&right))
+    } else {
+        Some(right.cmp(&left))
+    }
+}
+
+/// Computes `a ± b` as a reduced numerator and denominator pair
+#[inline]
+fn add_sub_parts<I: FractionInt>(
+    (a_num, a_den): (I, I),
+    (b_num, b_den): (I, I),
+    subtract: bool,
+) -> Result<(I, I), Error> {
+    let (a, b) = ((a_num, a_den), (b_num, b_den));
+    if let Some(parts) = add_sub_small::<i128, I>(&a, &b, subtract)
+        .or_else(|| add_sub_small::<I256, I>(&a, &b, subtract))
+    {
+        return Ok(parts);
+    }
+    let ((a_num, a_den), (b_num, b_den)) = (a, b);
+    let (left, right, denominator) = if a_den == b_den {
+        (a_num.widen(), b_num.widen(), a_den.widen())
+    } else {
+        (
+            a_num.widen() * b_den.widen(),
+            b_num.widen() * a_den.widen(),
+            a_den.widen() * b_den.widen(),
+        )
+    };
+    let numerator = if subtract {
+        left.checked_sub(&right)
+    } else {
+        left.checked_add(&right)
+    }
+    .ok_or(Error::Overflow(I::BITS))?;
+    reduce_wide(numerator, denominator)
+}
+
+/// Computes `a * b` as a reduced numerator and denominator pair
+#[inline]
+fn mul_parts<I: FractionInt>(
+    (a_num, a_den): (I, I),
+    (b_num, b_den): (I, I),
+) -> Result<(I, I), Error> {
+    let (a, b) = ((a_num, a_den), (b_num, b_den));
+    if let Some(parts) = mul_small::<i128, I>(&a, &b).or_else(|| mul_small::<I256, I>(&a, &b)) {
+        return Ok(parts);
+    }
+    let ((a_num, a_den), (b_num, b_den)) = (a, b);
+    reduce_wide(a_num.widen() * b_num.widen(), a_den.widen() * b_den.widen())
+}
+
+/// Computes `a / b` as a reduced numerator and denominator pair
+#[inline]
+fn div_parts<I: FractionInt>(
+    (a_num, a_den): (I, I),
+    (b_num, b_den): (I, I),
+) -> Result<(I, I), Error> {
+    // dividing by b is multiplying by its reciprocal
+    let (a, b) = ((a_num, a_den), (b_den, b_num));
+    if let Some(parts) = mul_small::<i128, I>(&a, &b).or_else(|| mul_small::<I256, I>(&a, &b)) {
+        return Ok(parts);
+    }
+    let ((a_num, a_den), (b_den, b_num)) = (a, b);
+    reduce_wide(a_num.widen() * b_den.widen(), a_den.widen() * b_num.widen())
+}
+
+/// Decomposes a finite `f64` into its exact, reduced numerator and denominator pair
+#[inline]
+fn f64_parts(value: f64) -> Result<(BigInt, BigInt), Error> {
+    if !value.is_finite() {
+        return Err(Error::NotFinite);
+    }
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    if mantissa == 0 {
+        return Ok((BigInt::ZERO, BigInt::ONE));
+    }
+    // strip the factors of two shared with the power of two denominator
+    let trailing_zeros = mantissa.trailing_zeros();
+    mantissa >>= trailing_zeros;
+    exponent += trailing_zeros as i32;
+    let mantissa_bits = (u64::BITS - mantissa.leading_zeros()) as i32;
+    let mut numerator = BigInt::from(mantissa as i64);
+    if value.is_sign_negative() {
+        numerator = -numerator;
+    }
+    if exponent >= 0 {
+        if mantissa_bits + exponent >= BigInt::BITS as i32 {
+            return Err(Error::Overflow(BigInt::BITS));
+        }
+        Ok((numerator << exponent as u32, BigInt::ONE))
+    } else {
+        if -exponent >= BigInt::BITS as i32 - 1 {
+            return Err(Error::Overflow(BigInt::BITS));
+        }
+        Ok((numerator, BigInt::ONE << exponent.unsigned_abs()))
+    }
+}
+
+/// Finds the closest fraction to `numerator / denominator` with a denominator of at most
+/// `max_denominator` using the continued fraction expansion
+#[inline]
+fn limit_denominator_parts(
+    numerator: BigInt,
+    denominator: BigInt,
+    max_denominator: BigInt,
+) -> Result<(BigInt, BigInt), Error> {
+    if max_denominator < BigInt::ONE {
+        return Err(Error::NonPositiveMaxDenominator);
+    }
+    if denominator.is_zero() {
+        return Err(Error::ZeroDenominator);
+    }
+    let gcd = numerator.gcd(&denominator);
+    let (numerator, denominator) = normalize_sign(numerator / gcd, denominator / gcd)?;
+    if denominator <= max_denominator {
+        return Ok((numerator, denominator));
+    }
+    let denominator: I1024 = denominator.cast();
+    let max_denominator: I1024 = max_denominator.cast();
+    let (mut p0, mut q0, mut p1, mut q1) = (I1024::ZERO, I1024::ONE, I1024::ONE, I1024::ZERO);
+    let (mut n, mut d): (I1024, I1024) = (numerator.cast(), denominator);
+    loop {
+        let a = n.div_floor(d);
+        let q2 = q0 + a * q1;
+        if q2 > max_denominator {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+        (n, d) = (d, n - a * d);
+    }
+    // the best approximation is either the last convergent or the best semiconvergent
+    let k = (max_denominator - q0) / q1;
+    let (p, q) = if (d * (q0 + k * q1)) << 1_u32 <= denominator {
+        (p1, q1)
+    } else {
+        (p0 + k * p1, q0 + k * q1)
+    };
+    Ok((narrow(p)?, narrow(q)?))
+}
+
+/// Returns `2^exp` for an exponent in the range of normal `f64` values
+#[inline]
+const fn pow2(exp: i32) -> f64 {
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// Converts `numerator / denominator` to the nearest `f64`
+#[inline]
+fn to_f64_parts(numerator: BigInt, denominator: BigInt) -> f64 {
+    if numerator.is_zero() {
+        return 0.0;
+    }
+    let negative = numerator.is_negative() != denominator.is_negative();
+    let numerator: I1024 = numerator.cast();
+    let denominator: I1024 = denominator.cast();
+    let (numerator, denominator) = (numerator.abs(), denominator.abs());
+    // scale the operands so that the integer quotient has 64 or 65 significant bits
+    let shift = 64 + denominator.bits() as i32 - numerator.bits() as i32;
+    let (numerator, denominator) = if shift >= 0 {
+        (numerator << shift as u32, denominator)
+    } else {
+        (numerator, denominator << shift.unsigned_abs())
+    };
+    let quotient = (numerator / denominator)
+        .to_u128()
+        .expect("quotient has at most 65 bits");
+    // a non-zero remainder becomes a sticky bit far below the rounding position of `f64`, so
+    // the integer to float conversion rounds correctly
+    let sticky = u128::from(!(numerator % denominator).is_zero());
+    // |numerator / denominator| lies within [2^-511, 2^511], so the scaling by a power of two is
+    // exact
+    let magnitude = (quotient | sticky) as f64 * pow2(-shift);
+    if negative { -magnitude } else { magnitude }
 }
 
 /// Trait defining common operations for fractions with metadata
@@ -90,8 +768,26 @@ where
 
 /// Trait defining common operations for fractions with metadata
 pub trait FractionBase<M: Clone>: Sized {
+    /// Fallible constructor method for creating a new Fraction with metadata
+    ///
+    /// Returns [`Error::ZeroDenominator`] if the denominator is zero and [`Error::Overflow`] if
+    /// normalizing the sign of a negative denominator overflows.
+    fn try_new(
+        numerator: impl Into<BigInt>,
+        denominator: impl Into<BigInt>,
+        meta: M,
+    ) -> Result<Self, Error>;
+
     /// Constructor method for creating a new Fraction with metadata
-    fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>, meta: M) -> Self;
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero, or if it is negative and normalizing the signs
+    /// overflows, e.g. for [`BigInt::MIN`]. Use [`FractionBase::try_new`] to handle these cases.
+    #[inline]
+    fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>, meta: M) -> Self {
+        Self::try_new(numerator, denominator, meta).unwrap_or_else(|e| panic!("{e}"))
+    }
 
     /// Accessor method for retrieving metadata
     fn meta(&self) -> &M;
@@ -108,6 +804,28 @@ pub trait FractionBase<M: Clone>: Sized {
         self.numerator().div_floor(self.denominator())
     }
 
+    /// Returns the quotient of the fraction rounded to a whole number with the given rounding
+    /// strategy
+    #[inline]
+    fn rounded_quotient(&self, rounding: Rounding) -> BigInt {
+        let numerator = self.numerator();
+        let denominator = self.denominator();
+        let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+        if remainder.is_zero() {
+            return quotient;
+        }
+        let negative = numerator.is_negative() != denominator.is_negative();
+        // |remainder| < |denominator| <= 2^511, so doubling it cannot overflow
+        let half = (remainder.unsigned_abs() << 1_u32).cmp(&denominator.unsigned_abs());
+        if !rounds_away_from_zero(rounding, negative, half, quotient.is_even()) {
+            quotient
+        } else if negative {
+            quotient - BigInt::ONE
+        } else {
+            quotient + BigInt::ONE
+        }
+    }
+
     /// Returns the remainder after floor division as a new fraction
     #[inline]
     fn remainder(&self) -> Self {
@@ -125,11 +843,85 @@ pub trait FractionBase<M: Clone>: Sized {
     }
 
     /// Converts the fraction to a [`BigDecimal`]
+    ///
+    /// The division is rounded to the precision of [`BigDecimal`]. Use
+    /// [`FractionBase::to_decimal_expansion`] or [`FractionBase::to_decimal_with_precision`] for
+    /// exact digits.
     #[inline]
     fn to_decimal(&self) -> BigDecimal {
         self.numerator().to_big_decimal() / self.denominator().to_big_decimal()
     }
 
+    /// Returns the exact decimal expansion of the fraction, including its repeating cycle, e.g.
+    /// `1/7 = 0.(142857)`.
+    ///
+    /// Returns [`Error::MaxDigitsExceeded`] if the non-repeating digits and the cycle together are
+    /// longer than `max_digits`. The cycle of `1/q` can have up to `q - 1` digits.
+    #[inline]
+    fn to_decimal_expansion(&self, max_digits: usize) -> Result<DecimalExpansion, Error> {
+        DecimalExpansion::new(self.numerator(), self.denominator(), max_digits)
+    }
+
+    /// Converts the fraction to a string with exactly `decimal_places` fraction digits, correctly
+    /// rounded with the given rounding strategy, [`Rounding::RoundHalfUp`] by default.
+    ///
+    /// Unlike [`FractionBase::to_fixed`], the digits are computed exactly for any number of
+    /// decimal places.
+    #[inline]
+    fn to_decimal_with_precision(
+        &self,
+        decimal_places: usize,
+        rounding: Option<Rounding>,
+    ) -> String {
+        decimal_with_precision(
+            self.numerator(),
+            self.denominator(),
+            decimal_places,
+            rounding.unwrap_or_default(),
+        )
+    }
+
+    /// Writes the fraction with a specified number of significant digits and rounding strategy
+    /// into a [`fmt::Write`] buffer without allocating
+    #[inline]
+    fn write_significant(
+        &self,
+        w: &mut impl fmt::Write,
+        significant_digits: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        if significant_digits == 0 {
+            return Err(Error::ZeroSignificantDigits);
+        }
+        Ok(write_significant_digits(
+            w,
+            self.numerator(),
+            self.denominator(),
+            0,
+            significant_digits as usize,
+            rounding.unwrap_or_default(),
+        )?)
+    }
+
+    /// Writes the fraction with a fixed number of decimal places and rounding strategy into a
+    /// [`fmt::Write`] buffer without allocating
+    #[inline]
+    fn write_fixed(
+        &self,
+        w: &mut impl fmt::Write,
+        decimal_places: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        Ok(write_fixed_digits(
+            w,
+            self.numerator(),
+            self.denominator(),
+            0,
+            decimal_places as usize,
+            rounding.unwrap_or_default(),
+        )?)
+    }
+
     /// Converts the fraction to a string with a specified number of significant digits and rounding
     /// strategy
     #[inline]
@@ -138,50 +930,257 @@ pub trait FractionBase<M: Clone>: Sized {
         significant_digits: u8,
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
-        if significant_digits == 0 {
-            return Err(Error::Invalid("SIGNIFICANT_DIGITS"));
-        }
-        let rounding_strategy = to_rounding_strategy(rounding.unwrap_or_default());
-        let quotient = self.to_decimal().with_rounding_mode(rounding_strategy);
-        let integer_digits = quotient.digits_count() as i16 - quotient.fractional_digits_count();
-        let quotient = quotient
-            .round(significant_digits as i16 - integer_digits)
-            .reduce();
-
-        Ok(quotient.to_string())
+        let mut s = String::new();
+        self.write_significant(&mut s, significant_digits, rounding)?;
+        Ok(s)
     }
 
     /// Converts the fraction to a string with a fixed number of decimal places and rounding
     /// strategy
     #[inline]
     fn to_fixed(&self, decimal_places: u8, rounding: Option<Rounding>) -> String {
-        let rounding_strategy = to_rounding_strategy(rounding.unwrap_or_default());
-        self.to_decimal()
-            .with_rounding_mode(rounding_strategy)
-            .round(decimal_places as i16)
-            .to_string()
+        let mut s = String::new();
+        // writing into a `String` cannot fail
+        let _ = self.write_fixed(&mut s, decimal_places, rounding);
+        s
+    }
+
+    /// Writes the fraction using a [`NumberFormat`] into a [`fmt::Write`] buffer without
+    /// allocating
+    #[inline]
+    fn write_formatted(&self, w: &mut impl fmt::Write, format: &NumberFormat) -> Result<(), Error> {
+        format.write(w, &self.as_fraction())
+    }
+
+    /// Converts the fraction to a string using a [`NumberFormat`]
+    #[inline]
+    fn to_formatted(&self, format: &NumberFormat) -> Result<String, Error> {
+        format.format(&self.as_fraction())
+    }
+
+    /// Creates a fraction holding the exact binary value of a finite `f64`, e.g. `0.1` becomes
+    /// `3602879701896397/36028797018963968`
+    ///
+    /// Returns [`Error::NotFinite`] if the value is NaN or infinite and [`Error::Overflow`] if the
+    /// value is too large or too small to be represented with a [`BigInt`] numerator and
+    /// denominator.
+    #[inline]
+    fn from_f64(value: f64, meta: M) -> Result<Self, Error> {
+        let (numerator, denominator) = f64_parts(value)?;
+        Self::try_new(numerator, denominator, meta)
+    }
+
+    /// Creates the closest fraction to a finite `f64` whose denominator is at most
+    /// `max_denominator`, e.g. `0.1` with a maximum denominator of `1000` becomes `1/10`
+    ///
+    /// See [`FractionBase::from_f64`] and [`FractionBase::limit_denominator`] for the errors.
+    #[inline]
+    fn approximate_f64(
+        value: f64,
+        max_denominator: impl Into<BigInt>,
+        meta: M,
+    ) -> Result<Self, Error> {
+        let (numerator, denominator) = f64_parts(value)?;
+        let (numerator, denominator) =
+            limit_denominator_parts(numerator, denominator, max_denominator.into())?;
+        Self::try_new(numerator, denominator, meta)
+    }
+
+    /// Returns the closest fraction to `self` whose denominator is at most `max_denominator`,
+    /// keeping the metadata of `self`. Ties are resolved in favour of the smaller denominator.
+    ///
+    /// Returns [`Error::NonPositiveMaxDenominator`] if `max_denominator` is less than one.
+    #[inline]
+    fn limit_denominator(&self, max_denominator: impl Into<BigInt>) -> Result<Self, Error> {
+        let (numerator, denominator) =
+            limit_denominator_parts(self.numerator(), self.denominator(), max_denominator.into())?;
+        Self::try_new(numerator, denominator, self.meta().clone())
+    }
+
+    /// Converts the fraction to the nearest `f64`.
+    ///
+    /// The result is correctly rounded, i.e. its relative error is at most `2^-53`. Since every
+    /// fraction of [`BigInt`]s lies within `2^-511` and `2^511` in magnitude, the result is
+    /// always finite and never subnormal. For a [`Price`] this is the raw ratio; use
+    /// [`Price::adjusted_for_decimals`] to account for the currency decimals.
+    #[inline]
+    fn to_f64(&self) -> f64 {
+        to_f64_parts(self.numerator(), self.denominator())
+    }
+
+    /// Returns whether the fraction is zero
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.numerator().is_zero()
+    }
+
+    /// Returns the absolute value of the fraction, keeping the metadata
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numerator or denominator is [`BigInt::MIN`], whose negation overflows.
+    #[inline]
+    fn abs(&self) -> Self {
+        Self::new(
+            self.numerator().abs(),
+            self.denominator().abs(),
+            self.meta().clone(),
+        )
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the fraction, keeping the metadata
+    #[inline]
+    fn signum(&self) -> Self {
+        let sign = self.numerator().signum() * self.denominator().signum();
+        Self::new(sign, BigInt::ONE, self.meta().clone())
+    }
+
+    /// Returns the canonical form of the fraction, i.e. reduced by the gcd of the numerator and
+    /// denominator with a positive denominator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the reduced denominator is [`BigInt::MIN`], whose negation overflows.
+    #[inline]
+    fn reduced(&self) -> Self {
+        let numerator = self.numerator();
+        let denominator = self.denominator();
+        let gcd = numerator.gcd(&denominator);
+        Self::new(numerator / gcd, denominator / gcd, self.meta().clone())
     }
 
     /// Helper method for converting any superclass back to a simple [`Fraction`]
     #[inline]
-    fn as_fraction(&self) -> Fraction {
-        Fraction::new(self.numerator(), self.denominator())
+    fn as_fraction(&self) -> Fraction {
+        Fraction::new(self.numerator(), self.denominator())
+    }
+
+    /// Adds another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::Overflow`] if the reduced result does not fit in [`BigInt`].
+    fn checked_add(&self, other: &Self) -> Result<Self, Error>;
+
+    /// Subtracts another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::Overflow`] if the reduced result does not fit in [`BigInt`].
+    fn checked_sub(&self, other: &Self) -> Result<Self, Error>;
+
+    /// Multiplies by another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::Overflow`] if the reduced result does not fit in [`BigInt`].
+    fn checked_mul(&self, other: &Self) -> Result<Self, Error>;
+
+    /// Divides by another fraction, keeping the metadata of `self`
+    ///
+    /// Returns [`Error::ZeroDenominator`] if `other` is zero and [`Error::Overflow`] if the
+    /// reduced result does not fit in [`BigInt`].
+    fn checked_div(&self, other: &Self) -> Result<Self, Error>;
+
+    /// Encodes the fraction as an unsigned fixed-point number with `frac_bits` fraction bits,
+    /// e.g. 96 for `sqrtPriceX96`, rounding the last bit with the given rounding strategy
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Negative`] if the fraction is negative, [`Error::FractionalBitsExceeded`]
+    /// if `frac_bits` exceeds [`MAX_Q_FRACTIONAL_BITS`] and [`Error::UintOverflow`] if the encoding
+    /// exceeds [`U256::MAX`].
+    #[inline]
+    fn to_q(&self, frac_bits: u32, rounding: Rounding) -> Result<U256, Error> {
+        to_q_parts(self.numerator(), self.denominator(), frac_bits, rounding)
+    }
+
+    /// Raises the fraction to an integer power, keeping the metadata of `self`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroDenominator`] for a negative power of zero and [`Error::Overflow`] if
+    /// the reduced result does not fit in [`BigInt`].
+    #[inline]
+    fn pow(&self, exponent: i32) -> Result<Self, Error> {
+        let (numerator, denominator) = pow_parts(self.numerator(), self.denominator(), exponent)?;
+        Self::try_new(numerator, denominator, self.meta().clone())
+    }
+
+    /// Returns the square root of the fraction with `precision` decimal places, rounded with the
+    /// given rounding strategy, keeping the metadata of `self`
+    ///
+    /// The result is exact up to the final rounding, so with [`Rounding::RoundHalfUp`] it is
+    /// within half of `10^-precision` of the exact root.
+    ///
+    /// # Errors
+    ///
+    /// See [`FractionBase::nth_root`].
+    #[inline]
+    fn sqrt(&self, precision: u8, rounding: Rounding) -> Result<Self, Error> {
+        self.nth_root(2, precision, rounding)
+    }
+
+    /// Returns the `n`th root of the fraction with `precision` decimal places, rounded with the
+    /// given rounding strategy, keeping the metadata of `self`
+    ///
+    /// Odd roots of negative fractions are negative. The result is exact up to the final
+    /// rounding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroRoot`] if `n` is zero, [`Error::Negative`] for an even root of a
+    /// negative fraction and
+    /// [`Error::Overflow`] if the fraction scaled by `10^(n * precision)` exceeds 1024 bits or the
+    /// result does not fit in [`BigInt`].
+    #[inline]
+    fn nth_root(&self, n: u32, precision: u8, rounding: Rounding) -> Result<Self, Error> {
+        let (numerator, denominator) =
+            nth_root_parts(self.numerator(), self.denominator(), n, precision, rounding)?;
+        Self::try_new(numerator, denominator, self.meta().clone())
+    }
+
+    /// Returns the logarithm of the fraction in the given base with `precision` decimal places,
+    /// keeping the metadata of `self`
+    ///
+    /// The result is within `10^-precision` of the exact logarithm: both binary logarithms are
+    /// computed with enough bits for their quotient to be within half of `10^-precision`, which is
+    /// then rounded half up.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotPositive`] if the fraction or the base is not positive,
+    /// [`Error::LogBaseOne`] if the base is one and [`Error::Overflow`] if the precision cannot be
+    /// reached within 500 bits, which limits it to about 140 decimal places and less for bases
+    /// very close to one.
+    #[inline]
+    fn log_base(&self, base: &Fraction, precision: u8) -> Result<Self, Error> {
+        let (numerator, denominator) = log_parts(
+            (self.numerator(), self.denominator()),
+            (base.numerator, base.denominator),
+            precision,
+        )?;
+        Self::try_new(numerator, denominator, self.meta().clone())
     }
 }
 
 impl<M: Clone + PartialEq> FractionTrait<M> for FractionLike<M> {}
 
 impl<M: Clone> FractionBase<M> for FractionLike<M> {
-    /// Constructor for creating a new [`FractionLike`] with metadata
+    /// Fallible constructor for creating a new [`FractionLike`] with metadata
+    ///
+    /// A negative denominator is normalized by flipping the signs of both the numerator and the
+    /// denominator.
     #[inline]
-    fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>, meta: M) -> Self {
+    fn try_new(
+        numerator: impl Into<BigInt>,
+        denominator: impl Into<BigInt>,
+        meta: M,
+    ) -> Result<Self, Error> {
         let denominator = denominator.into();
-        assert_ne!(denominator, BigInt::ZERO, "denominator is zero");
-        Self {
-            numerator: numerator.into(),
+        if denominator.is_zero() {
+            return Err(Error::ZeroDenominator);
+        }
+        let (numerator, denominator) = normalize_sign(numerator.into(), denominator)?;
+        Ok(Self {
+            numerator,
             denominator,
             meta,
-        }
+        })
     }
 
     /// Accessor method for retrieving metadata
@@ -201,127 +1200,264 @@ impl<M: Clone> FractionBase<M> for FractionLike<M> {
     fn denominator(&self) -> BigInt {
         self.denominator
     }
+
+    // the arithmetic is shared with the other backing integers through the inherent methods
+
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Result<Self, Error> {
+        Self::checked_add(self, other)
+    }
+
+    #[inline]
+    fn checked_sub(&self, other: &Self) -> Result<Self, Error> {
+        Self::checked_sub(self, other)
+    }
+
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Result<Self, Error> {
+        Self::checked_mul(self, other)
+    }
+
+    #[inline]
+    fn checked_div(&self, other: &Self) -> Result<Self, Error> {
+        Self::checked_div(self, other)
+    }
+}
+
+impl<M, I: FractionInt> FractionLike<M, I> {
+    /// Compares two fractions through the native fast paths if their parts are small enough
+    #[inline]
+    fn cmp_small(&self, other: &Self) -> Option<Ordering> {
+        let a = (self.numerator.clone(), self.denominator.clone());
+        let b = (other.numerator.clone(), other.denominator.clone());
+        cmp_small::<i128, I>(&a, &b).or_else(|| cmp_small::<I256, I>(&a, &b))
+    }
 }
 
-impl<M: PartialEq> PartialEq for FractionLike<M> {
+impl<M: PartialEq, I: FractionInt> PartialEq for FractionLike<M, I> {
     /// Checks if the current fraction is equal to another fraction
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        let left: I1024 = self.numerator.cast() * other.denominator.cast();
-        let right: I1024 = other.numerator.cast() * self.denominator.cast();
-        left == right && self.meta == other.meta
+        if self.meta != other.meta {
+            return false;
+        }
+        if self.denominator == other.denominator {
+            return self.numerator == other.numerator;
+        }
+        if let Some(ordering) = self.cmp_small(other) {
+            return ordering == Ordering::Equal;
+        }
+        let left = self.numerator.widen() * other.denominator.widen();
+        let right = other.numerator.widen() * self.denominator.widen();
+        left == right
     }
 }
 
-impl<M: PartialEq> Eq for FractionLike<M> {}
+impl<M: PartialEq, I: FractionInt> Eq for FractionLike<M, I> {}
 
-impl<M: Hash> Hash for FractionLike<M> {
-    /// Hashes the fraction using the numerator, denominator, and metadata
+impl<M: Hash, I: FractionInt> Hash for FractionLike<M, I> {
+    /// Hashes the canonical form of the fraction and its metadata, so that fractions which
+    /// compare equal (e.g. `1/3` and `-4/-12`) also hash equally.
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.numerator.hash(state);
-        self.denominator.hash(state);
+        let numerator = self.numerator.widen();
+        let denominator = self.denominator.widen();
+        let gcd = numerator.gcd(&denominator);
+        let negative = !numerator.is_zero() && numerator.is_negative() != denominator.is_negative();
+        negative.hash(state);
+        (numerator.abs() / gcd.clone()).hash(state);
+        (denominator.abs() / gcd).hash(state);
         self.meta.hash(state);
     }
 }
 
-impl<M: PartialEq> Ord for FractionLike<M> {
+impl<M: PartialEq, I: FractionInt> Ord for FractionLike<M, I> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        let left: I1024 = self.numerator.cast() * other.denominator.cast();
-        let right: I1024 = other.numerator.cast() * self.denominator.cast();
-        left.cmp(&right)
+        if self.denominator == other.denominator {
+            return if self.denominator < I::zero() {
+                other.numerator.cmp(&self.numerator)
+            } else {
+                self.numerator.cmp(&other.numerator)
+            };
+        }
+        if let Some(ordering) = self.cmp_small(other) {
+            return ordering;
+        }
+        let left = self.numerator.widen() * other.denominator.widen();
+        let right = other.numerator.widen() * self.denominator.widen();
+        // cross multiplication flips the ordering if exactly one denominator is negative
+        let zero = I::zero();
+        if (self.denominator < zero) == (other.denominator < zero) {
+            left.cmp(&right)
+        } else {
+            right.cmp(&left)
+        }
     }
 }
 
-impl<M: PartialEq> PartialOrd<Self> for FractionLike<M> {
+impl<M: PartialEq, I: FractionInt> PartialOrd<Self> for FractionLike<M, I> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-macro_rules! impl_add_sub {
-    ($trait:ident, $method:ident, $op:tt, $Rhs:ty) => {
-        impl<M: Clone> $trait<$Rhs> for FractionLike<M> {
-            type Output = Self;
-
-            #[inline]
-            fn $method(self, other: $Rhs) -> Self::Output {
-                if self.denominator == other.denominator {
-                    let numerator = self.numerator $op other.numerator;
-                    let gcd = numerator.gcd(&self.denominator);
-                    FractionBase::new(numerator / gcd, self.denominator / gcd, self.meta)
-                } else {
-                    let numerator: I1024 = self.numerator.cast() * other.denominator.cast()
-                        $op other.numerator.cast() * self.denominator.cast();
-                    let denominator: I1024 = self.denominator.cast() * other.denominator.cast();
-                    let gcd = numerator.gcd(&denominator);
-                    FractionBase::new(
-                        TryCast::<BigInt>::try_cast(numerator / gcd).unwrap(),
-                        TryCast::<BigInt>::try_cast(denominator / gcd).unwrap(),
-                        self.meta,
-                    )
+/// Unwraps the conversion of a primitive integer operand, panicking if it does not fit in `I`
+#[inline]
+fn int_operand<I: FractionInt>(value: Option<I>) -> I {
+    value.unwrap_or_else(|| panic!("{}", Error::Overflow(I::BITS)))
+}
+
+macro_rules! impl_op {
+    (
+        $generics:tt $Target:ty, $I:ty,
+        $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $parts:expr,
+        $($Rhs:ty => |$other:ident| $rhs_parts:expr),+ $(,)?
+    ) => {
+        $(
+            impl_op!(
+                @rhs $generics $Target, $I,
+                $trait, $method, $assign_trait, $assign_method, $parts,
+                $Rhs => |$other| $rhs_parts
+            );
+        )+
+    };
+    (
+        @rhs [$($generics:tt)*] $Target:ty, $I:ty,
+        $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $parts:expr,
+        $Rhs:ty => |$other:ident| $rhs_parts:expr
+    ) => {
+            impl<$($generics)*> $trait<$Rhs> for $Target {
+                type Output = Self;
+
+                /// # Panics
+                ///
+                /// Panics if the result overflows or the divisor is zero. Use the `checked_*`
+                /// methods of [`FractionBase`] to handle these cases.
+                #[inline]
+                fn $method(mut self, $other: $Rhs) -> Self::Output {
+                    self.$assign_method($other);
+                    self
                 }
             }
-        }
-    };
-}
 
-impl_add_sub!(Add, add, +, Self);
-impl_add_sub!(Add, add, +, &Self);
-impl_add_sub!(Sub, sub, -, Self);
-impl_add_sub!(Sub, sub, -, &Self);
-
-macro_rules! impl_mul {
-    ($trait:ident, $method:ident, $Rhs:ty) => {
-        impl<M: Clone> $trait<$Rhs> for FractionLike<M> {
-            type Output = Self;
-
-            #[inline]
-            fn $method(self, other: $Rhs) -> Self::Output {
-                let numerator: I1024 = self.numerator.cast() * other.numerator.cast();
-                let denominator: I1024 = self.denominator.cast() * other.denominator.cast();
-                let gcd = numerator.gcd(&denominator);
-                FractionBase::new(
-                    TryCast::<BigInt>::try_cast(numerator / gcd).unwrap(),
-                    TryCast::<BigInt>::try_cast(denominator / gcd).unwrap(),
-                    self.meta,
-                )
+            impl<$($generics)*> $assign_trait<$Rhs> for $Target {
+                /// # Panics
+                ///
+                /// Panics if the result overflows or the divisor is zero. Use the `checked_*`
+                /// methods of [`FractionBase`] to handle these cases.
+                #[inline]
+                fn $assign_method(&mut self, $other: $Rhs) {
+                    let numerator = mem::replace(&mut self.numerator, <$I as Zero>::zero());
+                    let denominator = mem::replace(&mut self.denominator, <$I as num_traits::One>::one());
+                    match $parts((numerator, denominator), $rhs_parts) {
+                        Ok((numerator, denominator)) => {
+                            self.numerator = numerator;
+                            self.denominator = denominator;
+                        }
+                        Err(e) => panic!("{e}"),
+                    }
+                }
             }
-        }
+    };
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $parts:expr) => {
+        impl_op!(
+            [M: Clone, I: FractionInt] FractionLike<M, I>, I,
+            $trait, $method, $assign_trait, $assign_method, $parts,
+            Self => |other| (other.numerator, other.denominator),
+            &Self => |other| (other.numerator.clone(), other.denominator.clone()),
+        );
+        // integer operands are not implemented for every metadata type, since a
+        // `CurrencyAmount` must stay within `MAX_UINT256` and implements them separately
+        impl_op!(
+            [I: FractionInt] FractionLike<(), I>, I,
+            $trait, $method, $assign_trait, $assign_method, $parts,
+            I => |other| (other, I::one()),
+            u64 => |other| (int_operand(I::from_u64(other)), I::one()),
+            i128 => |other| (int_operand(I::from_i128(other)), I::one()),
+        );
+        impl_op!(
+            [] Percent, BigInt,
+            $trait, $method, $assign_trait, $assign_method, $parts,
+            BigInt => |other| (other, BigInt::ONE),
+            u64 => |other| (int_operand(<BigInt as FromPrimitive>::from_u64(other)), BigInt::ONE),
+            i128 => |other| (int_operand(<BigInt as FromPrimitive>::from_i128(other)), BigInt::ONE),
+        );
+        impl_op!(
+            [TBase: BaseCurrency, TQuote: BaseCurrency] Price<TBase, TQuote>, BigInt,
+            $trait, $method, $assign_trait, $assign_method, $parts,
+            BigInt => |other| (other, BigInt::ONE),
+            u64 => |other| (int_operand(<BigInt as FromPrimitive>::from_u64(other)), BigInt::ONE),
+            i128 => |other| (int_operand(<BigInt as FromPrimitive>::from_i128(other)), BigInt::ONE),
+        );
     };
 }
 
-impl_mul!(Mul, mul, Self);
-impl_mul!(Mul, mul, &Self);
+impl_op!(Add, add, AddAssign, add_assign, |a, b| add_sub_parts(
+    a, b, false
+));
+impl_op!(Sub, sub, SubAssign, sub_assign, |a, b| add_sub_parts(
+    a, b, true
+));
+impl_op!(Mul, mul, MulAssign, mul_assign, mul_parts);
+impl_op!(Div, div, DivAssign, div_assign, div_parts);
 
-macro_rules! impl_div {
-    ($trait:ident, $method:ident, $Rhs:ty) => {
-        impl<M: Clone> $trait<$Rhs> for FractionLike<M> {
-            type Output = Self;
+impl<M, I: FractionInt> Neg for FractionLike<M, I> {
+    type Output = Self;
 
-            #[inline]
-            fn $method(self, other: $Rhs) -> Self::Output {
-                let numerator: I1024 = self.numerator.cast() * other.denominator.cast();
-                let denominator: I1024 = self.denominator.cast() * other.numerator.cast();
-                let gcd = numerator.gcd(&denominator);
-                FractionBase::new(
-                    TryCast::<BigInt>::try_cast(numerator / gcd).unwrap(),
-                    TryCast::<BigInt>::try_cast(denominator / gcd).unwrap(),
-                    self.meta,
-                )
-            }
+    /// # Panics
+    ///
+    /// Panics if the numerator is the minimum value of `I`, whose negation overflows.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: checked_negate(&self.numerator).unwrap_or_else(|e| panic!("{e}")),
+            ..self
         }
-    };
+    }
+}
+
+impl<M: Clone + Default, I: FractionInt> Sum for FractionLike<M, I> {
+    #[inline]
+    fn sum<It: Iterator<Item = Self>>(iter: It) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<'a, M: Clone + Default + 'a, I: FractionInt + 'a> Sum<&'a Self> for FractionLike<M, I> {
+    #[inline]
+    fn sum<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<M: Clone + Default, I: FractionInt> Product for FractionLike<M, I> {
+    #[inline]
+    fn product<It: Iterator<Item = Self>>(iter: It) -> Self {
+        let one = Self {
+            numerator: I::one(),
+            ..Self::default()
+        };
+        iter.fold(one, |acc, x| acc * x)
+    }
 }
 
-impl_div!(Div, div, Self);
-impl_div!(Div, div, &Self);
+impl<'a, M: Clone + Default + 'a, I: FractionInt + 'a> Product<&'a Self> for FractionLike<M, I> {
+    #[inline]
+    fn product<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        let one = Self {
+            numerator: I::one(),
+            ..Self::default()
+        };
+        iter.fold(one, |acc, x| acc * x)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
 
     #[test]
     fn test_quotient() {
@@ -330,6 +1466,65 @@ mod tests {
         assert_eq!(Fraction::new(16, 5).quotient(), BigInt::from(3));
     }
 
+    #[test]
+    fn test_rounded_quotient() {
+        use Rounding::*;
+        let modes = [
+            RoundDown,
+            RoundHalfUp,
+            RoundUp,
+            RoundHalfDown,
+            RoundHalfEven,
+            RoundCeiling,
+            RoundFloor,
+        ];
+        // value, then the expected result for each mode in the order above
+        let cases: [((i64, i64), [i64; 7]); 10] = [
+            ((55, 10), [5, 6, 6, 5, 6, 6, 5]),
+            ((25, 10), [2, 3, 3, 2, 2, 3, 2]),
+            ((16, 10), [1, 2, 2, 2, 2, 2, 1]),
+            ((11, 10), [1, 1, 2, 1, 1, 2, 1]),
+            ((10, 10), [1, 1, 1, 1, 1, 1, 1]),
+            ((-10, 10), [-1, -1, -1, -1, -1, -1, -1]),
+            ((-11, 10), [-1, -1, -2, -1, -1, -1, -2]),
+            ((-16, 10), [-1, -2, -2, -2, -2, -1, -2]),
+            ((-25, 10), [-2, -3, -3, -2, -2, -2, -3]),
+            ((55, -10), [-5, -6, -6, -5, -6, -5, -6]),
+        ];
+        for ((numerator, denominator), expected) in cases {
+            let f = Fraction {
+                numerator: BigInt::from(numerator),
+                denominator: BigInt::from(denominator),
+                meta: (),
+            };
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(
+                    f.rounded_quotient(mode),
+                    BigInt::from(expected),
+                    "{numerator}/{denominator} {mode:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_fixed_rounding_modes() {
+        let f = Fraction::new(-125, 100);
+        assert_eq!(f.to_fixed(1, Some(Rounding::RoundUp)), "-1.3");
+        assert_eq!(f.to_fixed(1, Some(Rounding::RoundCeiling)), "-1.2");
+        assert_eq!(f.to_fixed(1, Some(Rounding::RoundFloor)), "-1.3");
+        assert_eq!(f.to_fixed(1, Some(Rounding::RoundHalfEven)), "-1.2");
+        assert_eq!(f.to_fixed(1, Some(Rounding::RoundHalfDown)), "-1.2");
+        assert_eq!(
+            Fraction::new(135, 100).to_significant(2, Some(Rounding::RoundHalfEven)),
+            Ok("1.4".into())
+        );
+        assert_eq!(
+            Fraction::new(125, 100).to_significant(2, Some(Rounding::RoundHalfEven)),
+            Ok("1.2".into())
+        );
+    }
+
     #[test]
     fn test_remainder() {
         assert_eq!(Fraction::new(8, 3).remainder(), Fraction::new(2, 3));
@@ -422,6 +1617,852 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_new_zero_denominator() {
+        assert_eq!(Fraction::try_new(1, 0), Err(Error::ZeroDenominator));
+        assert_eq!(Fraction::try_new(1, 2), Ok(Fraction::new(1, 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator is zero")]
+    fn test_new_zero_denominator_panics() {
+        let _ = Fraction::new(1, 0);
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let a = Fraction::new(1, 10);
+        let b = Fraction::new(4, 12);
+        assert_eq!(a.checked_add(&b), Ok(a.clone() + b.clone()));
+        assert_eq!(a.checked_sub(&b), Ok(a.clone() - b.clone()));
+        assert_eq!(a.checked_mul(&b), Ok(a.clone() * b.clone()));
+        assert_eq!(a.checked_div(&b), Ok(a / b));
+    }
+
+    #[test]
+    fn test_checked_div_by_zero() {
+        assert_eq!(
+            Fraction::new(1, 2).checked_div(&Fraction::ZERO),
+            Err(Error::ZeroDenominator)
+        );
+        assert_eq!(
+            Percent::new(1, 2).checked_div(&Percent::ZERO),
+            Err(Error::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        let max = Fraction::new(BigInt::MAX, 1);
+        assert_eq!(max.checked_add(&max), Err(Error::Overflow(BigInt::BITS)));
+        assert_eq!(
+            max.checked_sub(&Fraction::new(-BigInt::MAX, 1)),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+        assert_eq!(max.checked_mul(&max), Err(Error::Overflow(BigInt::BITS)));
+        assert_eq!(
+            max.checked_div(&Fraction::new(1, BigInt::MAX)),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+        // the result fits after reduction
+        assert_eq!(max.checked_div(&max), Ok(Fraction::ONE));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator is zero")]
+    fn test_div_by_zero_panics() {
+        let _ = Fraction::new(1, 2) / Fraction::ZERO;
+    }
+
+    #[test]
+    #[should_panic(expected = "arithmetic overflow")]
+    fn test_mul_overflow_panics() {
+        let max = Fraction::new(BigInt::MAX, 1);
+        let _ = max.clone() * max;
+    }
+
+    #[test]
+    fn test_negative_denominator_is_normalized() {
+        let f = Fraction::new(1, -3);
+        assert_eq!(f.numerator, BigInt::from(-1));
+        assert_eq!(f.denominator, BigInt::from(3));
+        assert_eq!(Fraction::new(2, -4).invert(), Fraction::new(-2, 1));
+        assert_eq!(
+            Fraction::try_new(1, BigInt::MIN),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+    }
+
+    #[test]
+    fn test_cmp_with_raw_negative_denominator() {
+        let f = Fraction {
+            numerator: BigInt::ONE,
+            denominator: -BigInt::from(2),
+            meta: (),
+        };
+        assert!(f < Fraction::ZERO);
+        assert!(Fraction::ZERO > f);
+        assert_eq!(f, Fraction::new(-1, 2));
+    }
+
+    #[test]
+    fn test_reduced() {
+        let f = Fraction::new(-4, 12).reduced();
+        assert_eq!(f.numerator, BigInt::from(-1));
+        assert_eq!(f.denominator, BigInt::from(3));
+        assert_eq!(Fraction::new(0, 7).reduced().denominator, BigInt::ONE);
+    }
+
+    mod hash {
+        use super::*;
+        use crate::token;
+        use core::hash::BuildHasher;
+        use proptest::prelude::*;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            HashMap::<(), ()>::default().hasher().hash_one(value)
+        }
+
+        fn raw<M>(numerator: i64, denominator: i64, meta: M) -> FractionLike<M> {
+            FractionLike {
+                numerator: BigInt::from(numerator),
+                denominator: BigInt::from(denominator),
+                meta,
+            }
+        }
+
+        #[test]
+        fn equal_fractions_hash_equally() {
+            let pairs = [
+                (Fraction::new(1, 3), Fraction::new(4, 12)),
+                (Fraction::new(-1, 3), Fraction::new(4, -12)),
+                (Fraction::new(0, 5), Fraction::ZERO),
+                (raw(1, -3, ()), raw(-2, 6, ())),
+                (raw(-1, -3, ()), raw(2, 6, ())),
+                (raw(0, -3, ()), raw(0, 7, ())),
+            ];
+            for (a, b) in pairs {
+                assert_eq!(a, b);
+                assert_eq!(hash_of(&a), hash_of(&b));
+            }
+            assert_ne!(
+                hash_of(&Fraction::new(1, 3)),
+                hash_of(&Fraction::new(-1, 3))
+            );
+        }
+
+        #[test]
+        fn equal_percents_hash_equally() {
+            let a = Percent::new(50, 100);
+            let b = Percent::new(-1, -2);
+            assert_eq!(a, b);
+            assert_eq!(hash_of(&a), hash_of(&b));
+        }
+
+        #[test]
+        fn equal_prices_hash_equally() {
+            let token0 = token!(1, "0000000000000000000000000000000000000001", 18);
+            let token1 = token!(1, "0000000000000000000000000000000000000002", 6);
+            let a = Price::new(token0.clone(), token1.clone(), 3, 9000);
+            let b = Price::new(token0, token1, 1, 3000);
+            assert_eq!(a, b);
+            assert_eq!(hash_of(&a), hash_of(&b));
+        }
+
+        #[test]
+        fn equal_currency_amounts_hash_equally() {
+            let token = token!(1, "0000000000000000000000000000000000000001", 18);
+            let a = CurrencyAmount::from_fractional_amount(token.clone(), 10, 4).unwrap();
+            let b = CurrencyAmount::from_fractional_amount(token, 5, 2).unwrap();
+            assert_eq!(a, b);
+            assert_eq!(hash_of(&a), hash_of(&b));
+        }
+
+        #[test]
+        fn equal_fractions_are_one_map_key() {
+            let mut map: HashMap<Fraction, &str> = HashMap::default();
+            map.insert(Fraction::new(1, 3), "third");
+            assert_eq!(map.get(&Fraction::new(2, 6)), Some(&"third"));
+            assert_eq!(map.get(&raw(-3, -9, ())), Some(&"third"));
+        }
+
+        proptest! {
+            #[test]
+            fn eq_implies_hash_eq(
+                numerator in -1_000_000_i64..1_000_000,
+                denominator in (1_i64..1_000_000).prop_union(-1_000_000_i64..0),
+                k in (1_i64..1_000).prop_union(-1_000_i64..0),
+            ) {
+                let a = raw(numerator, denominator, IsPercent);
+                let b = raw(numerator * k, denominator * k, IsPercent);
+                prop_assert_eq!(&a, &b);
+                prop_assert_eq!(hash_of(&a), hash_of(&b));
+                prop_assert_eq!(hash_of(&a), hash_of(&a.reduced()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_significant_and_fixed() {
+        let f = Fraction::new(1, 3);
+        let mut buf = StackBuffer::<16>::new();
+        f.write_significant(&mut buf, 3, None).unwrap();
+        assert_eq!(buf.as_str(), "0.333");
+        buf.clear();
+        f.write_fixed(&mut buf, 5, Some(Rounding::RoundUp)).unwrap();
+        assert_eq!(buf.as_str(), "0.33334");
+        assert_eq!(
+            f.write_significant(&mut buf, 0, None),
+            Err(Error::ZeroSignificantDigits)
+        );
+        let mut small = StackBuffer::<4>::new();
+        assert_eq!(f.write_fixed(&mut small, 5, None), Err(Error::Fmt));
+    }
+
+    mod operators {
+        use super::*;
+
+        #[test]
+        fn integer_operands() {
+            let f = Fraction::new(1, 3);
+            assert_eq!(f.clone() + BigInt::ONE, Fraction::new(4, 3));
+            assert_eq!(f.clone() - 1_u64, Fraction::new(-2, 3));
+            assert_eq!(f.clone() * -6_i128, Fraction::new(-2, 1));
+            assert_eq!(f / 2_u64, Fraction::new(1, 6));
+            let percent = Percent::new(1, 100) * 50_u64;
+            assert_eq!(percent, Percent::new(1, 2));
+        }
+
+        #[test]
+        #[should_panic(expected = "denominator is zero")]
+        fn divide_by_zero_integer() {
+            let _ = Fraction::new(1, 3) / 0_u64;
+        }
+
+        #[test]
+        fn assign_operators() {
+            let mut f = Fraction::new(1, 2);
+            f += Fraction::new(1, 3);
+            assert_eq!(f, Fraction::new(5, 6));
+            f -= &Fraction::new(1, 6);
+            assert_eq!(f, Fraction::new(2, 3));
+            f *= 3_u64;
+            assert_eq!(f, Fraction::new(2, 1));
+            f /= BigInt::from(4);
+            assert_eq!(f, Fraction::new(1, 2));
+            assert_eq!((f.numerator, f.denominator), (BigInt::ONE, BigInt::from(2)));
+        }
+
+        #[test]
+        fn neg() {
+            assert_eq!(-Fraction::new(1, 3), Fraction::new(-1, 3));
+            assert_eq!(-Percent::new(-1, 3), Percent::new(1, 3));
+        }
+
+        #[test]
+        #[should_panic(expected = "arithmetic overflow")]
+        fn neg_overflow() {
+            let _ = -Fraction::new(BigInt::MIN, 1);
+        }
+
+        #[test]
+        fn sum_and_product() {
+            let fractions = [
+                Fraction::new(1, 2),
+                Fraction::new(1, 3),
+                Fraction::new(1, 6),
+            ];
+            assert_eq!(fractions.iter().sum::<Fraction>(), Fraction::ONE);
+            assert_eq!(fractions.iter().product::<Fraction>(), Fraction::new(1, 36));
+            assert_eq!(fractions.into_iter().sum::<Fraction>(), Fraction::ONE);
+            assert_eq!(
+                core::iter::empty::<Fraction>().sum::<Fraction>(),
+                Fraction::ZERO
+            );
+            assert_eq!(
+                core::iter::empty::<Percent>().product::<Percent>(),
+                Percent::new(1, 1)
+            );
+        }
+
+        #[test]
+        fn abs_signum_is_zero() {
+            assert_eq!(Fraction::new(-1, 3).abs(), Fraction::new(1, 3));
+            assert_eq!(Fraction::new(1, 3).abs(), Fraction::new(1, 3));
+            assert_eq!(Fraction::new(-7, 3).signum(), Fraction::new(-1, 1));
+            assert_eq!(Fraction::new(7, 3).signum(), Fraction::ONE);
+            assert_eq!(Fraction::new(0, 3).signum(), Fraction::ZERO);
+            assert!(Fraction::new(0, 3).is_zero());
+            assert!(!Fraction::new(1, 3).is_zero());
+        }
+
+        #[test]
+        fn min_max_clamp() {
+            let (a, b) = (Fraction::new(1, 3), Fraction::new(1, 2));
+            assert_eq!(a.clone().min(b.clone()), a);
+            assert_eq!(a.clone().max(b.clone()), b);
+            assert_eq!(Fraction::ONE.clamp(a.clone(), b.clone()), b);
+            assert_eq!(Fraction::ZERO.clamp(a.clone(), b), a);
+        }
+    }
+
+    mod float {
+        use super::*;
+        use fastnum::i512;
+        use proptest::prelude::*;
+
+        fn parts<M>(f: &FractionLike<M>) -> (BigInt, BigInt) {
+            (f.numerator, f.denominator)
+        }
+
+        #[test]
+        fn from_f64_is_exact() {
+            let f = Fraction::from_f64(0.1).unwrap();
+            assert_eq!(
+                parts(&f),
+                (
+                    BigInt::from(3602879701896397_i64),
+                    BigInt::from(36028797018963968_i64)
+                )
+            );
+            assert_eq!(
+                parts(&Fraction::from_f64(-2.5).unwrap()),
+                (i512!(-5), i512!(2))
+            );
+            assert_eq!(
+                parts(&Fraction::from_f64(-0.0).unwrap()),
+                (i512!(0), i512!(1))
+            );
+            assert_eq!(parts(&Fraction::from_f64(1e20).unwrap()).1, i512!(1));
+            let percent: Percent = FractionBase::from_f64(0.25, IsPercent).unwrap();
+            assert_eq!(percent, Percent::new(1, 4));
+        }
+
+        #[test]
+        fn from_f64_errors() {
+            assert_eq!(Fraction::from_f64(f64::NAN), Err(Error::NotFinite));
+            assert_eq!(Fraction::from_f64(f64::INFINITY), Err(Error::NotFinite));
+            assert_eq!(
+                Fraction::from_f64(f64::MAX),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+            assert_eq!(
+                Fraction::from_f64(1e-200),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+            assert!(Fraction::from_f64(2_f64.powi(509)).is_ok());
+            assert!(Fraction::from_f64(2_f64.powi(-509)).is_ok());
+        }
+
+        #[test]
+        fn approximate_f64() {
+            let f = Fraction::approximate_f64(0.1, 1000).unwrap();
+            assert_eq!(parts(&f), (i512!(1), i512!(10)));
+            let pi = Fraction::approximate_f64(core::f64::consts::PI, 1000).unwrap();
+            assert_eq!(parts(&pi), (i512!(355), i512!(113)));
+            let pi = Fraction::approximate_f64(-core::f64::consts::PI, 100).unwrap();
+            assert_eq!(parts(&pi), (i512!(-311), i512!(99)));
+            assert_eq!(
+                Fraction::approximate_f64(0.5, 0),
+                Err(Error::NonPositiveMaxDenominator)
+            );
+        }
+
+        #[test]
+        fn limit_denominator() {
+            let f = Fraction::new(3141592653589793_i64, 1000000000000000_i64);
+            assert_eq!(
+                parts(&f.limit_denominator(10).unwrap()),
+                (i512!(22), i512!(7))
+            );
+            assert_eq!(
+                parts(&f.limit_denominator(1).unwrap()),
+                (i512!(3), i512!(1))
+            );
+            // already within the limit once reduced
+            let f = Fraction::new(2, 4);
+            assert_eq!(
+                parts(&f.limit_denominator(3).unwrap()),
+                (i512!(1), i512!(2))
+            );
+            // ties go to the smaller denominator
+            let f = Fraction::new(3, 8);
+            assert_eq!(
+                parts(&f.limit_denominator(5).unwrap()),
+                (i512!(2), i512!(5))
+            );
+            let f = Fraction::new(-1, -3);
+            assert_eq!(
+                parts(&f.limit_denominator(2).unwrap()),
+                (i512!(1), i512!(2))
+            );
+            // metadata is preserved
+            let percent = Percent::new(1234567, 10000000);
+            assert_eq!(
+                percent.limit_denominator(100).unwrap(),
+                Percent::new(10, 81)
+            );
+        }
+
+        #[test]
+        fn to_f64() {
+            assert_eq!(Fraction::new(1, 10).to_f64(), 0.1);
+            assert_eq!(Fraction::new(-5, 2).to_f64(), -2.5);
+            assert_eq!(Fraction::new(1, -3).to_f64(), -1.0 / 3.0);
+            assert_eq!(Fraction::new(0, 7).to_f64(), 0.0);
+            assert_eq!(Fraction::new(BigInt::MAX, 1).to_f64(), 2_f64.powi(511));
+            assert_eq!(Fraction::new(1, BigInt::MAX).to_f64(), 2_f64.powi(-511));
+            // exactly halfway between two floats rounds to even
+            let halfway = (BigInt::ONE << 53_u32) + BigInt::ONE;
+            assert_eq!(Fraction::new(halfway, 1).to_f64(), 2_f64.powi(53));
+            // slightly above halfway rounds up thanks to the sticky bit
+            let above = (halfway << 64_u32) + BigInt::ONE;
+            assert_eq!(
+                Fraction::new(above, BigInt::ONE << 64_u32).to_f64(),
+                2_f64.powi(53) + 2.0
+            );
+        }
+
+        proptest! {
+            #[test]
+            fn f64_round_trip(value in -1e100_f64..1e100) {
+                let f = Fraction::from_f64(value).unwrap();
+                prop_assert_eq!(f.to_f64(), value);
+            }
+
+            #[test]
+            fn limit_denominator_is_bounded(
+                numerator in -1_000_000_000_i64..1_000_000_000,
+                denominator in 1_i64..1_000_000_000,
+                max_denominator in 1_i64..10_000,
+            ) {
+                let f = Fraction::new(numerator, denominator);
+                let limited = f.limit_denominator(max_denominator).unwrap();
+                prop_assert!(limited.denominator <= BigInt::from(max_denominator));
+                prop_assert!(limited.denominator > BigInt::ZERO);
+                // no fraction with a denominator up to the limit is closer
+                let error = |g: &Fraction| {
+                    let diff = g.clone() - f.clone();
+                    Fraction::new(diff.numerator.abs(), diff.denominator)
+                };
+                let q = Fraction::new(max_denominator, 1);
+                let floor = Fraction::new((f.clone() * q.clone()).quotient(), max_denominator);
+                let ceil = Fraction::new((f.clone() * q).quotient() + BigInt::ONE, max_denominator);
+                prop_assert!(error(&limited) <= error(&floor));
+                prop_assert!(error(&limited) <= error(&ceil));
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let f = Fraction::new(1, 3);
+        assert_eq!(format!("{f}"), "0.333333");
+        assert_eq!(format!("{f:.2}"), "0.33");
+        assert_eq!(format!("{f:#.2}"), "0.33");
+        assert_eq!(format!("{f:#.0}"), "0.3");
+        assert_eq!(format!("{:.3}", Fraction::new(2, 3)), "0.667");
+        assert_eq!(format!("{:#.3}", Fraction::new(123456, 1)), "123000");
+        assert_eq!(format!("{:.1}", Fraction::new(-5, 2)), "-2.5");
+        assert_eq!(format!("{:.2}", Fraction::new(5, 1)), "5.00");
+        assert_eq!(f.to_string(), f.to_significant(6, None).unwrap());
+    }
+
+    mod fast_paths {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Scales both parts past 128 bits so that only the wide path applies
+        fn scaled(f: &Fraction) -> Fraction {
+            let k = BigInt::ONE << 200_u32;
+            Fraction {
+                numerator: f.numerator * k,
+                denominator: f.denominator * k,
+                meta: (),
+            }
+        }
+
+        fn parts(f: &Fraction) -> (BigInt, BigInt) {
+            (f.numerator, f.denominator)
+        }
+
+        fn from_i128(numerator: i128, denominator: i128) -> Fraction {
+            Fraction::new(
+                BigInt::try_from(numerator).unwrap(),
+                BigInt::try_from(denominator).unwrap(),
+            )
+        }
+
+        #[test]
+        fn extreme_operands() {
+            let min = Fraction::new(i64::MIN, 1);
+            assert_eq!(
+                parts(&(min.clone() * &min)),
+                (BigInt::from(i64::MIN) * BigInt::from(i64::MIN), BigInt::ONE)
+            );
+            assert_eq!(
+                parts(&(min.clone() + &min)),
+                (BigInt::from(i64::MIN) * BigInt::TWO, BigInt::ONE)
+            );
+            let min = from_i128(i128::MIN, 3);
+            let sum = min.clone() + &min;
+            assert_eq!(parts(&sum), parts(&(scaled(&min) + &scaled(&min))));
+            let product = min * &from_i128(i128::MIN, i128::MAX);
+            assert_eq!(
+                product.denominator,
+                BigInt::from(3) * BigInt::try_from(i128::MAX).unwrap()
+            );
+        }
+
+        #[test]
+        fn equal_denominators() {
+            let raw = |numerator: i64, denominator: i64| Fraction {
+                numerator: BigInt::from(numerator),
+                denominator: BigInt::from(denominator),
+                meta: (),
+            };
+            assert!(raw(1, -3) > raw(2, -3));
+            assert!(raw(1, 3) < raw(2, 3));
+            assert_eq!(raw(2, 3), raw(2, 3));
+            assert_ne!(raw(2, 3), raw(1, 3));
+            // the sum is still reduced
+            assert_eq!(parts(&(raw(1, 4) + &raw(1, 4))), (BigInt::ONE, BigInt::TWO));
+        }
+
+        proptest! {
+            #[test]
+            fn matches_wide_path(
+                a in any::<i128>(),
+                b in any::<i128>().prop_filter("non-zero", |d| *d != 0),
+                c in any::<i64>().prop_filter("non-zero", |n| *n != 0),
+                d in any::<i64>().prop_filter("non-zero", |d| *d != 0),
+            ) {
+                let x = from_i128(a, b);
+                let y = Fraction::new(c, d);
+                let (wx, wy) = (scaled(&x), scaled(&y));
+                prop_assert_eq!(parts(&(x.clone() + &y)), parts(&(wx.clone() + &wy)));
+                prop_assert_eq!(parts(&(x.clone() - &y)), parts(&(wx.clone() - &wy)));
+                prop_assert_eq!(parts(&(x.clone() * &y)), parts(&(wx.clone() * &wy)));
+                prop_assert_eq!(parts(&(x.clone() / &y)), parts(&(wx.clone() / &wy)));
+                prop_assert_eq!(x.cmp(&y), wx.cmp(&wy));
+                prop_assert_eq!(x == y, wx == wy);
+            }
+        }
+    }
+
+    mod powers {
+        use super::*;
+
+        fn parts(f: &Fraction) -> (BigInt, BigInt) {
+            (f.numerator, f.denominator)
+        }
+
+        #[test]
+        fn pow() {
+            let f = Fraction::new(-4, 6);
+            assert_eq!(parts(&f.pow(3).unwrap()), parts(&Fraction::new(-8, 27)));
+            assert_eq!(parts(&f.pow(-2).unwrap()), parts(&Fraction::new(9, 4)));
+            assert_eq!(parts(&f.pow(0).unwrap()), parts(&Fraction::ONE));
+            assert_eq!(Fraction::ZERO.pow(-1), Err(Error::ZeroDenominator));
+            assert_eq!(
+                Fraction::new(2, 1).pow(511),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+            assert_eq!(
+                Fraction::new(1, 2).pow(-510).unwrap().numerator,
+                BigInt::ONE << 510_u32
+            );
+        }
+
+        #[test]
+        fn sqrt() {
+            let two = Fraction::new(2, 1);
+            assert_eq!(
+                two.sqrt(6, Rounding::RoundDown).unwrap(),
+                Fraction::new(1_414_213, 1_000_000)
+            );
+            assert_eq!(
+                two.sqrt(6, Rounding::RoundUp).unwrap(),
+                Fraction::new(1_414_214, 1_000_000)
+            );
+            assert_eq!(
+                Fraction::new(9, 4).sqrt(0, Rounding::RoundDown).unwrap(),
+                Fraction::ONE
+            );
+            // sqrt(2.25) = 1.5 is a tie at zero decimal places
+            let ties = Fraction::new(9, 4);
+            assert_eq!(
+                ties.sqrt(0, Rounding::RoundHalfEven).unwrap(),
+                Fraction::new(2, 1)
+            );
+            assert_eq!(
+                ties.sqrt(0, Rounding::RoundHalfDown).unwrap(),
+                Fraction::ONE
+            );
+            assert_eq!(
+                ties.sqrt(1, Rounding::RoundUp).unwrap(),
+                Fraction::new(3, 2)
+            );
+            assert_eq!(
+                Fraction::new(-1, 1).sqrt(2, Rounding::RoundDown),
+                Err(Error::negative(Fraction::new(-1, 1)))
+            );
+            let root = Fraction::new(MAX_UINT256, 1)
+                .sqrt(0, Rounding::RoundDown)
+                .unwrap();
+            assert_eq!(root.numerator, crate::utils::sqrt(MAX_UINT256).unwrap());
+        }
+
+        #[test]
+        fn nth_root() {
+            assert_eq!(
+                Fraction::new(-27, 8)
+                    .nth_root(3, 0, Rounding::RoundDown)
+                    .unwrap(),
+                Fraction::new(-1, 1)
+            );
+            assert_eq!(
+                Fraction::new(-27, 8)
+                    .nth_root(3, 1, Rounding::RoundFloor)
+                    .unwrap(),
+                Fraction::new(-3, 2)
+            );
+            // 10^(1/5) = 1.58489319...
+            let root = Fraction::new(10, 1)
+                .nth_root(5, 4, Rounding::RoundHalfUp)
+                .unwrap();
+            assert_eq!(root, Fraction::new(15849, 10000));
+            let root = Fraction::new(-10, 1)
+                .nth_root(5, 4, Rounding::RoundCeiling)
+                .unwrap();
+            assert_eq!(root, Fraction::new(-15848, 10000));
+            assert_eq!(
+                Fraction::ONE.nth_root(0, 4, Rounding::RoundDown),
+                Err(Error::ZeroRoot)
+            );
+        }
+
+        #[test]
+        fn log_base() {
+            let two = Fraction::new(2, 1);
+            assert_eq!(
+                Fraction::new(1024, 1).log_base(&two, 10).unwrap(),
+                Fraction::new(10, 1)
+            );
+            assert_eq!(
+                Fraction::new(1, 8).log_base(&two, 4).unwrap(),
+                Fraction::new(-3, 1)
+            );
+            // log_10(2) = 0.30102999566...
+            assert_eq!(
+                two.log_base(&Fraction::new(10, 1), 8).unwrap(),
+                Fraction::new(30_103_000, 100_000_000)
+            );
+            // the tick of a price of 2 is log_1.0001(2) = 6931.818...
+            let tick = two.log_base(&Fraction::new(10001, 10000), 3).unwrap();
+            assert_eq!(tick, Fraction::new(6_931_818, 1000));
+            assert_eq!(two.log_base(&Fraction::ONE, 4), Err(Error::LogBaseOne));
+        }
+
+        #[test]
+        fn log_base_error_is_bounded() {
+            let base = Fraction::new(10001, 10000);
+            for n in [3_i64, 7, 1_000_000_007, 1] {
+                for d in [2_i64, 9, 123_456_789] {
+                    let x = Fraction::new(n, d);
+                    let log = x.log_base(&base, 12).unwrap();
+                    // raising the base to the logarithm recovers x up to f64 precision
+                    let approx = base.to_f64().powf(log.to_f64());
+                    assert!((approx / x.to_f64() - 1.0).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    mod widths {
+        use super::*;
+        use fastnum::I256;
+
+        type Narrow = FractionLike<(), I256>;
+
+        fn narrow(numerator: i64, denominator: i64) -> Narrow {
+            Narrow::try_from_parts(I256::from(numerator), I256::from(denominator), ()).unwrap()
+        }
+
+        #[test]
+        fn try_from_parts() {
+            let f = narrow(4, -6);
+            assert_eq!(
+                (f.numerator, f.denominator),
+                (I256::from(-4), I256::from(6))
+            );
+            assert_eq!(f, narrow(-2, 3));
+            assert_eq!(
+                Narrow::try_from_parts(I256::ONE, I256::ZERO, ()),
+                Err(Error::ZeroDenominator)
+            );
+        }
+
+        #[test]
+        fn arithmetic_matches_big_int() {
+            let a = narrow(7, 12);
+            let b = narrow(-5, 18);
+            let big = |f: Narrow| f.try_cast::<BigInt>().unwrap();
+            assert_eq!(big(a.clone() + b.clone()), big(a.clone()) + big(b.clone()));
+            assert_eq!(big(a.clone() - &b), big(a.clone()) - big(b.clone()));
+            assert_eq!(big(a.clone() * &b), big(a.clone()) * big(b.clone()));
+            assert_eq!(big(a.clone() / &b), big(a.clone()) / big(b.clone()));
+            assert!(a > b);
+            assert_eq!([a, b].into_iter().sum::<Narrow>(), narrow(11, 36));
+        }
+
+        #[test]
+        fn overflow() {
+            let max = Narrow::try_from_parts(I256::MAX, I256::ONE, ()).unwrap();
+            assert_eq!(max.checked_add(&narrow(1, 1)), Err(Error::Overflow(256)));
+            assert_eq!(max.checked_mul(&narrow(2, 1)), Err(Error::Overflow(256)));
+            // intermediate products wider than 256 bits are fine as long as the result fits
+            assert_eq!(max.checked_mul(&narrow(1, 2)).unwrap().numerator, I256::MAX);
+            assert_eq!(max.checked_sub(&max), Ok(narrow(0, 1)));
+            assert_eq!(max.checked_div(&narrow(0, 1)), Err(Error::ZeroDenominator));
+        }
+
+        #[test]
+        #[should_panic(expected = "arithmetic overflow")]
+        fn overflow_panics_in_operators() {
+            let max = Narrow::try_from_parts(I256::MAX, I256::ONE, ()).unwrap();
+            let _ = max * 2_u64;
+        }
+
+        #[test]
+        fn try_cast() {
+            let f = Fraction::new(-3, 8);
+            assert_eq!(f.clone().try_cast::<I256>(), Ok(narrow(-3, 8)));
+            assert_eq!(narrow(-3, 8).try_cast::<BigInt>(), Ok(f));
+            let wide = Fraction::new(MAX_UINT256, 1);
+            assert_eq!(wide.try_cast::<I256>(), Err(Error::Overflow(256)));
+        }
+
+        #[cfg(feature = "num-bigint")]
+        #[test]
+        fn heap_backed() {
+            type Heap = FractionLike<(), num_bigint::BigInt>;
+            let max = Fraction::new(BigInt::MAX, 1)
+                .try_cast::<num_bigint::BigInt>()
+                .unwrap();
+            let squared: Heap = max.clone() * &max;
+            assert!(squared.numerator.bits() > 1000);
+            assert_eq!(
+                squared.try_cast::<BigInt>(),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+            let half = Heap::try_from_parts(1.into(), 2.into(), ()).unwrap();
+            assert_eq!(
+                (max * half).try_cast::<BigInt>(),
+                Ok(Fraction::new(BigInt::MAX, 2))
+            );
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        fn parse(s: &str) -> Fraction {
+            s.parse().unwrap()
+        }
+
+        #[test]
+        fn parses_decimals() {
+            assert_eq!(parse("123.456"), Fraction::new(123456, 1000));
+            assert_eq!(parse("-0.5"), Fraction::new(-1, 2));
+            assert_eq!(parse("+.25"), Fraction::new(1, 4));
+            assert_eq!(parse("7."), Fraction::new(7, 1));
+            assert_eq!(parse("  42 "), Fraction::new(42, 1));
+            assert_eq!(parse("0.000"), Fraction::ZERO);
+        }
+
+        #[test]
+        fn parses_scientific_notation() {
+            assert_eq!(parse("1e-18"), Fraction::new(1, BigInt::from(10).pow(18)));
+            assert_eq!(parse("1.5E3"), Fraction::new(1500, 1));
+            assert_eq!(parse("-2.5e+2"), Fraction::new(-250, 1));
+            assert_eq!(parse("12.34e-1"), Fraction::new(1234, 1000));
+        }
+
+        #[test]
+        fn ignores_redundant_zeros() {
+            let zeros = "0".repeat(200);
+            assert_eq!(parse(&format!("0.1{zeros}")), Fraction::new(1, 10));
+            assert_eq!(parse(&format!("{zeros}12.5")), Fraction::new(25, 2));
+            assert_eq!(parse(&format!("1{zeros}e-200")), Fraction::new(1, 1));
+            assert_eq!(
+                parse(&format!("-1.{zeros}e-150")),
+                Fraction::new(-1, BigInt::TEN.pow(150))
+            );
+            assert_eq!(parse(&format!("0.{zeros}")), Fraction::ZERO);
+            assert_eq!(parse(&format!("0.{zeros}1e200")), Fraction::new(1, 10));
+            assert_eq!(
+                Fraction::from_decimal_str(&format!("1.{zeros}1")),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+        }
+
+        #[test]
+        fn parses_rationals() {
+            assert_eq!(parse("3/7"), Fraction::new(3, 7));
+            assert_eq!(parse("-6 / 14"), Fraction::new(-3, 7));
+            assert_eq!(parse("1.5/0.5"), Fraction::new(3, 1));
+        }
+
+        #[test]
+        fn returns_reduced_form() {
+            let f = parse("123.456");
+            assert_eq!(f.numerator, BigInt::from(15432));
+            assert_eq!(f.denominator, BigInt::from(125));
+        }
+
+        #[test]
+        fn rejects_malformed_input() {
+            for s in [
+                "", ".", "-", "abc", "1.2.3", "1e", "1e-", "e5", "0x10", "1/", "1/2/3",
+            ] {
+                assert_eq!(
+                    Fraction::from_decimal_str(s),
+                    Err(Error::InvalidNumber(s.into())),
+                    "{s}"
+                );
+            }
+            assert_eq!(
+                Fraction::from_decimal_str("1/0"),
+                Err(Error::ZeroDenominator)
+            );
+        }
+
+        #[test]
+        fn rejects_overflow() {
+            assert_eq!(
+                Fraction::from_decimal_str("1e200"),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+            assert_eq!(
+                Fraction::from_decimal_str("1e-200"),
+                Err(Error::Overflow(BigInt::BITS))
+            );
+            assert_eq!(
+                Fraction::from_decimal_str("1e99999999999999999999"),
+                Err(Error::Overflow(i64::BITS))
+            );
+            assert_eq!(
+                Fraction::from_decimal_str("1.5e-9223372036854775807"),
+                Err(Error::Overflow(i64::BITS))
+            );
+            assert_eq!(
+                Fraction::from_decimal_str("0.5e-9223372036854775808"),
+                Err(Error::Overflow(i64::BITS))
+            );
+        }
+    }
+
     #[test]
     fn test_as_faction() {
         let f = Fraction::new(1, 2);
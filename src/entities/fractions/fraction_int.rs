@@ -0,0 +1,203 @@
+use crate::prelude::*;
+use core::{fmt, hash::Hash, ops::Neg};
+use fastnum::{Cast, I64, I128, I256, I1024, TryCast};
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedSub, FromPrimitive, One, Signed, ToPrimitive, Zero};
+
+/// Integer type backing the numerator and denominator of a [`FractionLike`].
+///
+/// Products and sums are computed in [`FractionInt::Wide`] and narrowed back afterwards, so the
+/// width of `Self` only bounds the reduced results, not the intermediate values. [`BigInt`] is the
+/// default; narrower types such as [`I256`] are considerably faster when the values are known to
+/// fit in 256 bits.
+///
+/// Operands that fit in `i128` take native fast paths regardless of `Self`, through the
+/// [`ToPrimitive`] and [`FromPrimitive`] conversions.
+pub trait FractionInt:
+    Clone + Ord + Hash + fmt::Debug + Zero + One + FromPrimitive + ToPrimitive
+{
+    /// Signed integer type able to hold the product of any two values of `Self`
+    type Wide: Clone
+        + Ord
+        + Hash
+        + Integer
+        + Signed
+        + CheckedAdd
+        + CheckedSub
+        + Neg<Output = Self::Wide>;
+
+    /// Number of bits of the integer type, reported by [`Error::Overflow`] when a value does not
+    /// fit
+    const BITS: u32;
+
+    /// Losslessly converts the value to [`FractionInt::Wide`]
+    fn widen(&self) -> Self::Wide;
+
+    /// Converts a wide value back to `Self`, returning `None` if it does not fit
+    fn narrow(wide: Self::Wide) -> Option<Self>;
+
+    /// Converts the value to [`BigInt`], returning `None` if it does not fit
+    fn to_big_int(&self) -> Option<BigInt>;
+
+    /// Converts a [`BigInt`] to `Self`, returning `None` if it does not fit
+    fn from_big_int(value: BigInt) -> Option<Self>;
+}
+
+macro_rules! impl_fraction_int {
+    ($($int:ty => $wide:ty),+ $(,)?) => {
+        $(
+            impl FractionInt for $int {
+                type Wide = $wide;
+
+                const BITS: u32 = <$int>::BITS;
+
+                #[inline]
+                fn widen(&self) -> Self::Wide {
+                    (*self).cast()
+                }
+
+                #[inline]
+                fn narrow(wide: Self::Wide) -> Option<Self> {
+                    wide.try_cast().ok()
+                }
+
+                #[inline]
+                fn to_big_int(&self) -> Option<BigInt> {
+                    Some((*self).cast())
+                }
+
+                #[inline]
+                fn from_big_int(value: BigInt) -> Option<Self> {
+                    value.try_cast().ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_fraction_int!(I64 => I128, I128 => I256, I256 => BigInt);
+
+impl FractionInt for BigInt {
+    type Wide = I1024;
+
+    const BITS: u32 = Self::BITS;
+
+    #[inline]
+    fn widen(&self) -> Self::Wide {
+        (*self).cast()
+    }
+
+    #[inline]
+    fn narrow(wide: Self::Wide) -> Option<Self> {
+        wide.try_cast().ok()
+    }
+
+    #[inline]
+    fn to_big_int(&self) -> Option<BigInt> {
+        Some(*self)
+    }
+
+    #[inline]
+    fn from_big_int(value: BigInt) -> Option<Self> {
+        Some(value)
+    }
+}
+
+/// Heap-backed integers never overflow, so they serve as their own wide type.
+#[cfg(feature = "num-bigint")]
+impl FractionInt for num_bigint::BigInt {
+    type Wide = Self;
+
+    /// Heap-backed integers are unbounded
+    const BITS: u32 = u32::MAX;
+
+    #[inline]
+    fn widen(&self) -> Self::Wide {
+        self.clone()
+    }
+
+    #[inline]
+    fn narrow(wide: Self::Wide) -> Option<Self> {
+        Some(wide)
+    }
+
+    #[inline]
+    fn to_big_int(&self) -> Option<BigInt> {
+        let (sign, bytes) = self.to_bytes_le();
+        let magnitude = BigUint::from_le_slice(&bytes)?;
+        let negative = sign == num_bigint::Sign::Minus;
+        // the magnitude of `BigInt::MIN` exceeds `BigInt::MAX` by one
+        let limit = if negative {
+            BigInt::MIN.unsigned_abs()
+        } else {
+            BigInt::MAX.to_bits()
+        };
+        if magnitude > limit {
+            return None;
+        }
+        let value = BigInt::from_bits(magnitude);
+        Some(if negative {
+            value.wrapping_neg()
+        } else {
+            value
+        })
+    }
+
+    #[inline]
+    fn from_big_int(value: BigInt) -> Option<Self> {
+        let sign = if value.is_negative() {
+            num_bigint::Sign::Minus
+        } else {
+            num_bigint::Sign::Plus
+        };
+        let bytes: Vec<u8> = value
+            .unsigned_abs()
+            .digits()
+            .iter()
+            .flat_map(|digit| digit.to_le_bytes())
+            .collect();
+        Some(Self::from_bytes_le(sign, &bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widen_and_narrow() {
+        let value = I256::MAX;
+        assert_eq!(I256::narrow(value.widen() * value.widen()), None);
+        assert_eq!(I256::narrow(value.widen()), Some(value));
+        assert_eq!(BigInt::narrow(BigInt::MIN.widen()), Some(BigInt::MIN));
+    }
+
+    #[test]
+    fn test_big_int_round_trip() {
+        let value = I256::MIN;
+        assert_eq!(I256::from_big_int(value.to_big_int().unwrap()), Some(value));
+        assert_eq!(I256::from_big_int(BigInt::MAX), None);
+        assert_eq!(I64::from_big_int(BigInt::from(-42)), Some(I64::from(-42)));
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_num_bigint_round_trip() {
+        for value in [
+            BigInt::MAX,
+            -BigInt::MAX,
+            BigInt::MIN,
+            BigInt::ZERO,
+            BigInt::from(-12345),
+        ] {
+            let heap = num_bigint::BigInt::from_big_int(value).unwrap();
+            assert_eq!(heap.to_big_int(), Some(value));
+        }
+        let too_big = num_bigint::BigInt::from(1) << 600_u32;
+        assert_eq!(too_big.to_big_int(), None);
+        let above_max = num_bigint::BigInt::from(1) << 511_u32;
+        assert_eq!(above_max.to_big_int(), None);
+        let below_min: num_bigint::BigInt = -above_max - 1;
+        assert_eq!(below_min.to_big_int(), None);
+    }
+}
@@ -0,0 +1,393 @@
+use crate::prelude::*;
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+/// Closed interval of fractions, used to bound the error accumulated by a chain of rounded
+/// computations.
+///
+/// Arithmetic on intervals yields the smallest interval containing every result of the operation
+/// on values taken from the operands, so a computation carried out on intervals bounds the
+/// outcome of the same computation on any exact inputs within them.
+///
+/// ```
+/// use uniswap_sdk_core::prelude::*;
+///
+/// // an amount of 1000 that was rounded down from an unknown exact value
+/// let amount = FractionInterval::from_rounded(Fraction::new(1000, 1), Rounding::RoundDown);
+/// let price = Fraction::new(3, 2);
+/// let quoted = (amount * price).rounded(Rounding::RoundDown);
+/// assert_eq!(quoted.lower, Fraction::new(1500, 1));
+/// assert_eq!(quoted.upper, Fraction::new(1501, 1));
+/// assert_eq!(quoted.width(), Fraction::ONE);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FractionInterval<M = ()> {
+    /// Lower bound of the interval, inclusive
+    pub lower: FractionLike<M>,
+    /// Upper bound of the interval, inclusive
+    pub upper: FractionLike<M>,
+}
+
+/// Returns the sign of a fraction as `-1`, `0` or `1`
+#[inline]
+fn signum<M>(value: &FractionLike<M>) -> BigInt {
+    value.numerator.signum() * value.denominator.signum()
+}
+
+impl<M: Clone + PartialEq> FractionInterval<M> {
+    /// Creates an interval from its bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInterval`] if `lower` is greater than `upper`.
+    #[inline]
+    pub fn new(lower: FractionLike<M>, upper: FractionLike<M>) -> Result<Self, Error> {
+        if lower > upper {
+            return Err(Error::InvalidInterval);
+        }
+        Ok(Self { lower, upper })
+    }
+
+    /// Creates an interval holding a single exact value
+    #[inline]
+    #[must_use]
+    pub fn point(value: FractionLike<M>) -> Self {
+        Self {
+            lower: value.clone(),
+            upper: value,
+        }
+    }
+
+    /// Creates the interval of the exact values which round to `value` when rounded to a whole
+    /// number with the given rounding strategy.
+    ///
+    /// Use this to account for the rounding of an input, e.g. a raw token amount that was
+    /// rounded down when it was computed.
+    #[inline]
+    #[must_use]
+    pub fn from_rounded(value: FractionLike<M>, rounding: Rounding) -> Self {
+        let offset = |numerator: i64, denominator: i64| FractionLike {
+            numerator: BigInt::from(numerator),
+            denominator: BigInt::from(denominator),
+            meta: value.meta.clone(),
+        };
+        // offsets of the lower and upper bounds from the value, in halves of a unit
+        let (below, above) = match (rounding, signum(&value).cmp(&BigInt::ZERO)) {
+            (Rounding::RoundHalfUp | Rounding::RoundHalfDown | Rounding::RoundHalfEven, _) => {
+                (1, 1)
+            }
+            (Rounding::RoundDown, Ordering::Equal) => (2, 2),
+            (Rounding::RoundUp, Ordering::Equal) => (0, 0),
+            (Rounding::RoundFloor, _)
+            | (Rounding::RoundDown, Ordering::Greater)
+            | (Rounding::RoundUp, Ordering::Less) => (0, 2),
+            (Rounding::RoundCeiling, _)
+            | (Rounding::RoundDown, Ordering::Less)
+            | (Rounding::RoundUp, Ordering::Greater) => (2, 0),
+        };
+        let (below, above) = (offset(below, 2), offset(above, 2));
+        Self {
+            lower: value.clone() - below,
+            upper: value + above,
+        }
+    }
+
+    /// Returns the distance between the bounds
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> FractionLike<M> {
+        self.upper.clone() - &self.lower
+    }
+
+    /// Returns `true` if the value lies within the interval
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, value: &FractionLike<M>) -> bool {
+        &self.lower <= value && value <= &self.upper
+    }
+
+    /// Rounds both bounds to whole numbers with the given rounding strategy.
+    ///
+    /// Rounding is monotonic, so the result contains every rounded value of the interval.
+    #[inline]
+    #[must_use]
+    pub fn rounded(&self, rounding: Rounding) -> Self {
+        let round = |bound: &FractionLike<M>| FractionLike {
+            numerator: bound.rounded_quotient(rounding),
+            denominator: BigInt::ONE,
+            meta: bound.meta.clone(),
+        };
+        Self {
+            lower: round(&self.lower),
+            upper: round(&self.upper),
+        }
+    }
+
+    /// Creates the smallest interval containing all of the given candidate bounds
+    #[inline]
+    fn hull(candidates: [FractionLike<M>; 4]) -> Self {
+        let [first, rest @ ..] = candidates;
+        let (lower, upper) =
+            rest.into_iter()
+                .fold((first.clone(), first), |(lower, upper), candidate| {
+                    if candidate < lower {
+                        (candidate, upper)
+                    } else if candidate > upper {
+                        (lower, candidate)
+                    } else {
+                        (lower, upper)
+                    }
+                });
+        Self { lower, upper }
+    }
+
+    /// Adds another interval
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if a bound does not fit in [`BigInt`].
+    #[inline]
+    pub fn checked_add(&self, other: &Self) -> Result<Self, Error> {
+        Ok(Self {
+            lower: self.lower.checked_add(&other.lower)?,
+            upper: self.upper.checked_add(&other.upper)?,
+        })
+    }
+
+    /// Subtracts another interval
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if a bound does not fit in [`BigInt`].
+    #[inline]
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, Error> {
+        Ok(Self {
+            lower: self.lower.checked_sub(&other.upper)?,
+            upper: self.upper.checked_sub(&other.lower)?,
+        })
+    }
+
+    /// Multiplies by another interval
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if a bound does not fit in [`BigInt`].
+    #[inline]
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, Error> {
+        Ok(Self::hull([
+            self.lower.checked_mul(&other.lower)?,
+            self.lower.checked_mul(&other.upper)?,
+            self.upper.checked_mul(&other.lower)?,
+            self.upper.checked_mul(&other.upper)?,
+        ]))
+    }
+
+    /// Divides by another interval
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroDenominator`] if `other` contains zero and [`Error::Overflow`] if a
+    /// bound does not fit in [`BigInt`].
+    #[inline]
+    pub fn checked_div(&self, other: &Self) -> Result<Self, Error> {
+        if signum(&other.lower) * signum(&other.upper) <= BigInt::ZERO {
+            return Err(Error::ZeroDenominator);
+        }
+        Ok(Self::hull([
+            self.lower.checked_div(&other.lower)?,
+            self.lower.checked_div(&other.upper)?,
+            self.upper.checked_div(&other.lower)?,
+            self.upper.checked_div(&other.upper)?,
+        ]))
+    }
+}
+
+impl<M: Clone + PartialEq> From<FractionLike<M>> for FractionInterval<M> {
+    #[inline]
+    fn from(value: FractionLike<M>) -> Self {
+        Self::point(value)
+    }
+}
+
+macro_rules! impl_interval_op {
+    (
+        $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident,
+        $($Rhs:ty => |$other:ident| $rhs:expr),+ $(,)?
+    ) => {
+        $(
+            impl<M: Clone + PartialEq> $trait<$Rhs> for FractionInterval<M> {
+                type Output = Self;
+
+                /// # Panics
+                ///
+                /// Panics if a bound overflows or the divisor contains zero. Use the `checked_*`
+                /// methods to handle these cases.
+                #[inline]
+                fn $method(mut self, $other: $Rhs) -> Self::Output {
+                    self.$assign_method($other);
+                    self
+                }
+            }
+
+            impl<M: Clone + PartialEq> $assign_trait<$Rhs> for FractionInterval<M> {
+                /// # Panics
+                ///
+                /// Panics if a bound overflows or the divisor contains zero. Use the `checked_*`
+                /// methods to handle these cases.
+                #[inline]
+                fn $assign_method(&mut self, $other: $Rhs) {
+                    *self = self.$checked(&$rhs).unwrap_or_else(|e| panic!("{e}"));
+                }
+            }
+        )+
+    };
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident) => {
+        impl_interval_op!(
+            $trait, $method, $assign_trait, $assign_method, $checked,
+            Self => |other| other,
+            &Self => |other| other.clone(),
+            FractionLike<M> => |other| Self::point(other),
+            &FractionLike<M> => |other| Self::point(other.clone()),
+        );
+    };
+}
+
+impl_interval_op!(Add, add, AddAssign, add_assign, checked_add);
+impl_interval_op!(Sub, sub, SubAssign, sub_assign, checked_sub);
+impl_interval_op!(Mul, mul, MulAssign, mul_assign, checked_mul);
+impl_interval_op!(Div, div, DivAssign, div_assign, checked_div);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+
+    fn interval(lower: (i64, i64), upper: (i64, i64)) -> FractionInterval {
+        FractionInterval::new(
+            Fraction::new(lower.0, lower.1),
+            Fraction::new(upper.0, upper.1),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            FractionInterval::new(Fraction::ONE, Fraction::ZERO),
+            Err(Error::InvalidInterval)
+        );
+        let point = FractionInterval::point(Fraction::new(1, 3));
+        assert_eq!(point.width(), Fraction::ZERO);
+        assert!(point.contains(&Fraction::new(2, 6)));
+        assert!(!point.contains(&Fraction::new(1, 2)));
+    }
+
+    #[test]
+    fn test_from_rounded() {
+        let bounds = |value: i64, rounding: Rounding| {
+            let interval = FractionInterval::from_rounded(Fraction::new(value, 1), rounding);
+            (interval.lower, interval.upper)
+        };
+        let whole = |n: i64| Fraction::new(n, 1);
+        assert_eq!(bounds(5, Rounding::RoundDown), (whole(5), whole(6)));
+        assert_eq!(bounds(-5, Rounding::RoundDown), (whole(-6), whole(-5)));
+        assert_eq!(bounds(0, Rounding::RoundDown), (whole(-1), whole(1)));
+        assert_eq!(bounds(5, Rounding::RoundUp), (whole(4), whole(5)));
+        assert_eq!(bounds(-5, Rounding::RoundUp), (whole(-5), whole(-4)));
+        assert_eq!(bounds(0, Rounding::RoundUp), (whole(0), whole(0)));
+        assert_eq!(bounds(-5, Rounding::RoundFloor), (whole(-5), whole(-4)));
+        assert_eq!(bounds(5, Rounding::RoundCeiling), (whole(4), whole(5)));
+        assert_eq!(
+            bounds(5, Rounding::RoundHalfEven),
+            (Fraction::new(9, 2), Fraction::new(11, 2))
+        );
+        // every exact value in the interval rounds back to the value
+        for rounding in [
+            Rounding::RoundDown,
+            Rounding::RoundUp,
+            Rounding::RoundFloor,
+            Rounding::RoundCeiling,
+        ] {
+            for value in [-3, 0, 3] {
+                let interval = FractionInterval::from_rounded(Fraction::new(value, 1), rounding);
+                for exact in [&interval.lower, &interval.upper] {
+                    let inside = (exact.clone() * 999_u64 + Fraction::new(value, 1)) / 1000_u64;
+                    assert_eq!(inside.rounded_quotient(rounding), BigInt::from(value));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = interval((1, 1), (2, 1));
+        let b = interval((-1, 2), (1, 3));
+        assert_eq!(a.clone() + &b, interval((1, 2), (7, 3)));
+        assert_eq!(a.clone() - &b, interval((2, 3), (5, 2)));
+        let mut c = a.clone();
+        c += Fraction::new(1, 2);
+        assert_eq!(c, interval((3, 2), (5, 2)));
+        assert_eq!((a.clone() - a).width(), Fraction::new(2, 1));
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let a = interval((-2, 1), (3, 1));
+        let b = interval((-5, 1), (4, 1));
+        assert_eq!(a.clone() * &b, interval((-15, 1), (12, 1)));
+        assert_eq!(a.clone() * Fraction::new(-1, 1), interval((-3, 1), (2, 1)));
+        let c = interval((1, 2), (2, 1));
+        assert_eq!(a.clone() / &c, interval((-4, 1), (6, 1)));
+        assert_eq!(a.checked_div(&b), Err(Error::ZeroDenominator));
+        assert_eq!(
+            c.checked_div(&interval((0, 1), (1, 1))),
+            Err(Error::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator is zero")]
+    fn test_div_by_interval_containing_zero_panics() {
+        let _ = interval((1, 1), (2, 1)) / interval((-1, 1), (1, 1));
+    }
+
+    #[test]
+    fn test_multi_hop_bounds_exact_result() {
+        // amounts rounded down at every hop stay within the interval of the exact computation
+        let exact_in = Fraction::new(1_000_000_007, 1000);
+        let prices = [
+            Fraction::new(1997, 1000),
+            Fraction::new(3, 7),
+            Fraction::new(100, 99),
+        ];
+        let mut interval = FractionInterval::from_rounded(
+            Fraction::new(exact_in.rounded_quotient(Rounding::RoundDown), 1),
+            Rounding::RoundDown,
+        );
+        let mut exact = exact_in;
+        let mut rounded = interval.lower.clone();
+        for price in prices {
+            interval = (interval * &price).rounded(Rounding::RoundDown);
+            exact *= &price;
+            rounded = Fraction::new((rounded * &price).rounded_quotient(Rounding::RoundDown), 1);
+            assert!(interval.contains(&rounded));
+            assert!(interval.contains(&Fraction::new(
+                exact.rounded_quotient(Rounding::RoundDown),
+                1
+            )));
+        }
+        assert!(interval.width() <= Fraction::new(3, 1));
+    }
+
+    #[test]
+    fn test_currency_amount_interval() {
+        let token = token!(1, "0x0000000000000000000000000000000000000001", 6);
+        let amount = CurrencyAmount::from_raw_amount(token, 1000).unwrap();
+        let interval = FractionInterval::from_rounded(amount.clone(), Rounding::RoundHalfUp);
+        assert_eq!(interval.lower.meta, amount.meta);
+        assert!(interval.contains(&amount));
+        assert_eq!(interval.width().as_fraction(), Fraction::ONE);
+    }
+}
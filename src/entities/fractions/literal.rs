@@ -0,0 +1,228 @@
+//! Const parsing behind the [`fraction!`](crate::fraction), [`percent!`](crate::percent) and
+//! [`bps!`](crate::bps) macros. Invalid literals panic, which fails compilation when evaluated in
+//! a const context.
+
+use crate::prelude::*;
+
+/// Creates a const [`Fraction`] from a decimal literal or a ratio of two decimal literals.
+///
+/// The result is reduced and validated at compile time.
+///
+/// ```
+/// use uniswap_sdk_core::{fraction, prelude::*};
+///
+/// const ONE_THIRD: Fraction = fraction!(1 / 3);
+/// assert_eq!(ONE_THIRD, Fraction::new(1, 3));
+/// assert_eq!(fraction!(-2.5), Fraction::new(-5, 2));
+/// assert_eq!(fraction!(1.5 / 0.25), Fraction::new(6, 1));
+/// ```
+///
+/// A zero denominator is rejected at compile time:
+///
+/// ```compile_fail
+/// use uniswap_sdk_core::{fraction, prelude::*};
+///
+/// const INVALID: Fraction = fraction!(1 / 0);
+/// ```
+#[macro_export]
+macro_rules! fraction {
+    ($numerator:literal / $denominator:literal) => {
+        const {
+            $crate::entities::fractions::literal::fraction_literal(
+                stringify!($numerator),
+                stringify!($denominator),
+            )
+        }
+    };
+    ($value:literal) => {
+        const { $crate::entities::fractions::literal::fraction_literal(stringify!($value), "1") }
+    };
+}
+
+/// Creates a const [`Percent`] from a decimal literal expressed in percent, e.g. `percent!(0.5)`
+/// is `0.5%`.
+///
+/// ```
+/// use uniswap_sdk_core::{percent, prelude::*};
+///
+/// const DEFAULT_SLIPPAGE: Percent = percent!(0.5);
+/// assert_eq!(DEFAULT_SLIPPAGE, Percent::new(5, 1000));
+/// ```
+///
+/// Malformed decimals are rejected at compile time:
+///
+/// ```compile_fail
+/// use uniswap_sdk_core::{percent, prelude::*};
+///
+/// const INVALID: Percent = percent!(1e3);
+/// ```
+#[macro_export]
+macro_rules! percent {
+    ($value:literal) => {
+        const { $crate::entities::fractions::literal::percent_literal(stringify!($value), "100") }
+    };
+}
+
+/// Creates a const [`Percent`] from a decimal literal expressed in basis points, e.g. `bps!(30)`
+/// is `0.3%`.
+///
+/// ```
+/// use uniswap_sdk_core::{bps, prelude::*};
+///
+/// const FEE_TIER: Percent = bps!(30);
+/// assert_eq!(FEE_TIER, Percent::new(3, 1000));
+/// ```
+#[macro_export]
+macro_rules! bps {
+    ($value:literal) => {
+        const { $crate::entities::fractions::literal::percent_literal(stringify!($value), "10000") }
+    };
+}
+
+/// Unwraps the result of a checked operation, failing const evaluation on overflow
+macro_rules! const_unwrap {
+    ($value:expr) => {
+        match $value {
+            Some(value) => value,
+            None => panic!("fraction literal overflows BigInt"),
+        }
+    };
+}
+
+/// Parses an optionally negative decimal literal such as `-1_000.25` into a numerator and a power
+/// of ten denominator
+const fn parse_decimal_literal(s: &str) -> (BigInt, BigInt) {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    let negative = !bytes.is_empty() && bytes[0] == b'-';
+    if negative {
+        i += 1;
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+    }
+    let mut numerator = BigInt::ZERO;
+    let mut denominator = BigInt::ONE;
+    let mut digits = 0;
+    let mut fractional = false;
+    while i < bytes.len() {
+        match bytes[i] {
+            digit @ b'0'..=b'9' => {
+                numerator = const_unwrap!(numerator.checked_mul(BigInt::TEN));
+                numerator = const_unwrap!(numerator.checked_add(BigInt::from_u8(digit - b'0')));
+                if fractional {
+                    denominator = const_unwrap!(denominator.checked_mul(BigInt::TEN));
+                }
+                digits += 1;
+            }
+            b'_' => {}
+            b'.' if !fractional => fractional = true,
+            _ => panic!("invalid decimal in fraction literal"),
+        }
+        i += 1;
+    }
+    assert!(digits > 0, "invalid decimal in fraction literal");
+    if negative {
+        numerator = numerator.neg();
+    }
+    (numerator, denominator)
+}
+
+/// Returns the greatest common divisor of two non-negative integers
+const fn gcd(mut a: BigInt, mut b: BigInt) -> BigInt {
+    while !b.is_zero() {
+        let r = a.rem(b);
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Parses the ratio of two decimal literals into reduced numerator and denominator parts
+const fn ratio_literal(numerator: &str, denominator: &str) -> (BigInt, BigInt) {
+    let (a, b) = parse_decimal_literal(numerator);
+    let (c, d) = parse_decimal_literal(denominator);
+    assert!(!c.is_zero(), "fraction literal has a zero denominator");
+    // (a / b) / (c / d) = (a * d) / (b * c)
+    let mut numerator = const_unwrap!(a.checked_mul(d));
+    let mut denominator = const_unwrap!(b.checked_mul(c));
+    if denominator.is_negative() {
+        numerator = numerator.neg();
+        denominator = denominator.neg();
+    }
+    let abs_numerator = if numerator.is_negative() {
+        numerator.neg()
+    } else {
+        numerator
+    };
+    let gcd = gcd(abs_numerator, denominator);
+    (numerator.div(gcd), denominator.div(gcd))
+}
+
+/// Builds a [`Fraction`] from the ratio of two decimal literals
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn fraction_literal(numerator: &str, denominator: &str) -> Fraction {
+    let (numerator, denominator) = ratio_literal(numerator, denominator);
+    Fraction {
+        numerator,
+        denominator,
+        meta: (),
+    }
+}
+
+/// Builds a [`Percent`] from the ratio of two decimal literals
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn percent_literal(numerator: &str, denominator: &str) -> Percent {
+    let (numerator, denominator) = ratio_literal(numerator, denominator);
+    Percent {
+        numerator,
+        denominator,
+        meta: IsPercent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction() {
+        const HALF: Fraction = fraction!(1 / 2);
+        assert_eq!(
+            (HALF.numerator, HALF.denominator),
+            (BigInt::ONE, BigInt::TWO)
+        );
+        let f = fraction!(-6 / 4);
+        assert_eq!(
+            (f.numerator, f.denominator),
+            (BigInt::from(-3), BigInt::TWO)
+        );
+        let f = fraction!(3 / -9);
+        assert_eq!(
+            (f.numerator, f.denominator),
+            (BigInt::from(-1), BigInt::from(3))
+        );
+        assert_eq!(fraction!(1_000), Fraction::new(1000, 1));
+        assert_eq!(fraction!(0.125), Fraction::new(1, 8));
+        assert_eq!(fraction!(0 / 5), Fraction::ZERO);
+        assert_eq!(
+            fraction!(
+                115792089237316195423570985008687907853269984665640564039457584007913129639935
+            ),
+            Fraction::new(MAX_UINT256, 1)
+        );
+    }
+
+    #[test]
+    fn test_percent_and_bps() {
+        assert_eq!(percent!(0.5), Percent::new(1, 200));
+        assert_eq!(percent!(100), Percent::new(1, 1));
+        assert_eq!(bps!(30), Percent::new(3, 1000));
+        assert_eq!(bps!(0.5), Percent::new(1, 20000));
+        assert_eq!(bps!(30), percent!(0.3));
+    }
+}
@@ -0,0 +1,272 @@
+use super::fraction::rounds_away_from_zero;
+use crate::prelude::*;
+use fastnum::{Cast, I1024, TryCast, U1024};
+use num_integer::Roots;
+
+/// Largest number of fraction bits carried by [`log2_fixed`], so that squaring its fixed point
+/// values stays within [`U1024`]
+const MAX_LOG_BITS: u32 = 500;
+
+/// Narrows a wide magnitude back to a [`BigInt`] with the given sign
+#[inline]
+fn narrow_signed(magnitude: U1024, negative: bool) -> Result<BigInt, Error> {
+    let magnitude: BigUint = magnitude
+        .try_cast()
+        .map_err(|_| Error::Overflow(BigInt::BITS))?;
+    if magnitude > BigInt::MAX.to_bits() {
+        return Err(Error::Overflow(BigInt::BITS));
+    }
+    let value = BigInt::from_bits(magnitude);
+    Ok(if negative { -value } else { value })
+}
+
+/// Returns `10^precision` as the denominator of a result with `precision` decimal places
+#[inline]
+fn decimal_unit(precision: u8) -> Result<BigInt, Error> {
+    BigInt::TEN
+        .checked_pow(precision as u32)
+        .ok_or(Error::Overflow(BigInt::BITS))
+}
+
+/// Computes `(numerator / denominator)^exponent` as a reduced numerator and denominator pair
+#[inline]
+pub(crate) fn pow_parts(
+    numerator: BigInt,
+    denominator: BigInt,
+    exponent: i32,
+) -> Result<(BigInt, BigInt), Error> {
+    let gcd = numerator.gcd(&denominator);
+    let (mut numerator, mut denominator) = (numerator / gcd, denominator / gcd);
+    if exponent < 0 {
+        if numerator.is_zero() {
+            return Err(Error::ZeroDenominator);
+        }
+        (numerator, denominator) = (denominator, numerator);
+    }
+    let exponent = exponent.unsigned_abs();
+    Ok((
+        numerator
+            .checked_pow(exponent)
+            .ok_or(Error::Overflow(BigInt::BITS))?,
+        denominator
+            .checked_pow(exponent)
+            .ok_or(Error::Overflow(BigInt::BITS))?,
+    ))
+}
+
+/// Computes the `n`th root of `numerator / denominator` rounded to `precision` decimal places
+#[inline]
+pub(crate) fn nth_root_parts(
+    numerator: BigInt,
+    denominator: BigInt,
+    n: u32,
+    precision: u8,
+    rounding: Rounding,
+) -> Result<(BigInt, BigInt), Error> {
+    if n == 0 {
+        return Err(Error::ZeroRoot);
+    }
+    let negative = !numerator.is_zero() && numerator.is_negative() != denominator.is_negative();
+    if negative && n.is_multiple_of(2) {
+        return Err(Error::negative(Fraction::new(numerator, denominator)));
+    }
+    let unit = decimal_unit(precision)?;
+    // scaling the radicand by 10^(n * precision) scales its root by 10^precision
+    let scale = n
+        .checked_mul(precision as u32)
+        .and_then(U1024::checked_power_of_ten)
+        .ok_or(Error::Overflow(BigInt::BITS))?;
+    let numerator: U1024 = numerator.unsigned_abs().cast();
+    let denominator: U1024 = denominator.unsigned_abs().cast();
+    let scaled = numerator
+        .checked_mul(scale)
+        .ok_or(Error::Overflow(BigInt::BITS))?;
+    let root = (scaled / denominator).nth_root(n);
+
+    let checked_pow = |value: U1024| value.checked_pow(n).ok_or(Error::Overflow(BigInt::BITS));
+    let power = checked_pow(root)?
+        .checked_mul(denominator)
+        .ok_or(Error::Overflow(BigInt::BITS))?;
+    let root = if power == scaled {
+        root
+    } else {
+        // the exact root exceeds `root + 1/2` iff 2^n * scaled > (2 * root + 1)^n * denominator
+        let left = checked_pow(U1024::TWO)?
+            .checked_mul(scaled)
+            .ok_or(Error::Overflow(BigInt::BITS))?;
+        let right = checked_pow((root << 1_u32) + U1024::ONE)?
+            .checked_mul(denominator)
+            .ok_or(Error::Overflow(BigInt::BITS))?;
+        if rounds_away_from_zero(rounding, negative, left.cmp(&right), root.is_even()) {
+            root + U1024::ONE
+        } else {
+            root
+        }
+    };
+    Ok((narrow_signed(root, negative)?, unit))
+}
+
+/// Approximates `log2(numerator / denominator) * 2^bits` for a positive fraction, within an
+/// absolute error of 2.
+///
+/// The integer part is read from the bit lengths, then each fraction bit is obtained by squaring
+/// the normalized mantissa in fixed point with two guard bits. The truncation of every squaring
+/// contributes at most `3 * 2^-(bits + 2)` in total and cutting the expansion after `bits` bits at
+/// most `2^-bits`.
+#[inline]
+fn log2_fixed(numerator: BigUint, denominator: BigUint, bits: u32) -> I1024 {
+    let fixed_bits = bits + 2;
+    let numerator: U1024 = numerator.cast();
+    let denominator: U1024 = denominator.cast();
+    // 2^(exponent - 1) < numerator / denominator < 2^(exponent + 1)
+    let mut exponent = numerator.bits() as i32 - denominator.bits() as i32;
+    let shifted = |exponent: i32| {
+        if exponent >= 0 {
+            (numerator, denominator << exponent as u32)
+        } else {
+            (numerator << exponent.unsigned_abs(), denominator)
+        }
+    };
+    let (left, right) = shifted(exponent);
+    if left < right {
+        exponent -= 1;
+    }
+    // the mantissa in [1, 2) as a fixed point number with `fixed_bits` fraction bits
+    let shift = fixed_bits as i32 - exponent;
+    let mut mantissa = if shift >= 0 {
+        (numerator << shift as u32) / denominator
+    } else {
+        numerator / (denominator << shift.unsigned_abs())
+    };
+    let two = U1024::ONE << (fixed_bits + 1);
+    let mut log = I1024::from(exponent) << bits;
+    for bit in (0..bits).rev() {
+        mantissa = (mantissa * mantissa) >> fixed_bits;
+        if mantissa >= two {
+            mantissa >>= 1_u32;
+            log += I1024::ONE << bit;
+        }
+    }
+    log
+}
+
+/// Computes the logarithm of `numerator / denominator` in the given base rounded to `precision`
+/// decimal places, within `10^-precision` of the exact value
+#[inline]
+pub(crate) fn log_parts(
+    (numerator, denominator): (BigInt, BigInt),
+    (base_numerator, base_denominator): (BigInt, BigInt),
+    precision: u8,
+) -> Result<(BigInt, BigInt), Error> {
+    let positive = |n: BigInt, d: BigInt| !n.is_zero() && n.is_negative() == d.is_negative();
+    if !positive(numerator, denominator) {
+        return Err(Error::not_positive(Fraction::new(numerator, denominator)));
+    }
+    if !positive(base_numerator, base_denominator) {
+        return Err(Error::not_positive(Fraction::new(
+            base_numerator,
+            base_denominator,
+        )));
+    }
+    if base_numerator == base_denominator {
+        return Err(Error::LogBaseOne);
+    }
+    let unit = decimal_unit(precision)?;
+    let scale: I1024 = unit.cast();
+    // start with enough bits for the requested decimal places and double them until the
+    // approximations of both logarithms are known to be precise enough
+    let mut bits = precision as u32 * 10 / 3 + 16;
+    loop {
+        let log = log2_fixed(numerator.unsigned_abs(), denominator.unsigned_abs(), bits);
+        let base_log = log2_fixed(
+            base_numerator.unsigned_abs(),
+            base_denominator.unsigned_abs(),
+            bits,
+        );
+        // with errors of at most 2 in both fixed point logarithms, the error of the quotient is
+        // at most 2 * (|log| + |base_log|) / (|base_log| - 2)^2, which must not exceed half of
+        // 10^-precision
+        let (magnitude, base_magnitude) = (log.abs(), base_log.abs());
+        let margin = base_magnitude - I1024::TWO;
+        let error_bound = (magnitude + base_magnitude)
+            .checked_mul(scale << 2_u32)
+            .and_then(|bound| margin.checked_mul(margin).map(|square| (bound, square)));
+        match error_bound {
+            Some((bound, square)) if margin > I1024::ZERO && bound <= square => {
+                let (quotient, remainder) = (log * scale).div_rem(base_log);
+                let round_up = (remainder.abs() << 1_u32) >= base_magnitude;
+                let negative = log.is_negative() != base_log.is_negative();
+                let quotient =
+                    quotient.unsigned_abs() + if round_up { U1024::ONE } else { U1024::ZERO };
+                return Ok((narrow_signed(quotient, negative)?, unit));
+            }
+            _ if bits >= MAX_LOG_BITS => return Err(Error::Overflow(BigInt::BITS)),
+            _ => bits = (bits * 2).min(MAX_LOG_BITS),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(n: i64, d: i64) -> (BigInt, BigInt) {
+        (BigInt::from(n), BigInt::from(d))
+    }
+
+    #[test]
+    fn test_log2_fixed() {
+        let log = |n: u64, d: u64, bits: u32| log2_fixed(BigUint::from(n), BigUint::from(d), bits);
+        assert!((log(8, 1, 20) - (I1024::from(3) << 20_u32)).abs() <= I1024::TWO);
+        assert!((log(1, 8, 20) + (I1024::from(3) << 20_u32)).abs() <= I1024::TWO);
+        // log2(3) = 1.584962500721156...
+        let expected = I1024::from(1_661_953_i64);
+        assert!((log(3, 1, 20) - expected).abs() <= I1024::TWO);
+        assert!((log(1, 3, 20) + expected).abs() <= I1024::TWO);
+    }
+
+    #[test]
+    fn test_log2_fixed_extremes() {
+        let max = BigInt::MAX.unsigned_abs();
+        let log = log2_fixed(max, BigUint::ONE, MAX_LOG_BITS);
+        assert!((log - (I1024::from(511) << MAX_LOG_BITS)).abs() <= I1024::TWO);
+        let log = log2_fixed(BigUint::ONE, max, MAX_LOG_BITS);
+        assert!((log + (I1024::from(511) << MAX_LOG_BITS)).abs() <= I1024::TWO);
+    }
+
+    #[test]
+    fn test_log_parts_errors() {
+        assert_eq!(
+            log_parts(parts(0, 1), parts(2, 1), 4),
+            Err(Error::not_positive(Fraction::ZERO))
+        );
+        assert_eq!(
+            log_parts(parts(2, 1), parts(-2, 1), 4),
+            Err(Error::not_positive(Fraction::new(-2, 1)))
+        );
+        assert_eq!(
+            log_parts(parts(2, 1), parts(3, 3), 4),
+            Err(Error::LogBaseOne)
+        );
+        assert_eq!(
+            log_parts(parts(2, 1), parts(3, 1), 200),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+    }
+
+    #[test]
+    fn test_nth_root_parts_errors() {
+        assert_eq!(
+            nth_root_parts(BigInt::TWO, BigInt::ONE, 0, 2, Rounding::RoundDown),
+            Err(Error::ZeroRoot)
+        );
+        assert_eq!(
+            nth_root_parts(-BigInt::TWO, BigInt::ONE, 2, 2, Rounding::RoundDown),
+            Err(Error::negative(Fraction::new(-2, 1)))
+        );
+        assert_eq!(
+            nth_root_parts(BigInt::MAX, BigInt::ONE, 2, 100, Rounding::RoundDown),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+    }
+}
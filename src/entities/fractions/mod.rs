@@ -1,9 +1,22 @@
 pub mod currency_amount;
+pub mod decimal_expansion;
+pub mod fixed_point;
 pub mod fraction;
+pub mod fraction_int;
+pub mod interval;
+#[doc(hidden)]
+pub mod literal;
+mod math;
+pub mod number_format;
 pub mod percent;
 pub mod price;
 
 pub use currency_amount::*;
+pub use decimal_expansion::*;
+pub use fixed_point::*;
 pub use fraction::*;
+pub use fraction_int::*;
+pub use interval::*;
+pub use number_format::*;
 pub use percent::*;
 pub use price::*;
@@ -0,0 +1,501 @@
+use super::decimal_expansion::write_big_uint;
+use crate::prelude::*;
+use core::fmt::{self, Write};
+
+/// Suffixes used by compact notation, one per power of a thousand
+const COMPACT_SUFFIXES: [char; 4] = ['K', 'M', 'B', 'T'];
+
+/// Unicode subscript digits used to render the length of a run of zeros
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+/// Large enough to hold the decimal digits of any [`BigInt`]
+const DIGITS_CAPACITY: usize = 160;
+
+/// Options for rendering fractions, prices and currency amounts in a human-friendly way.
+///
+/// The defaults render up to 6 fraction digits with trailing zeros trimmed, `.` as the decimal
+/// separator and no thousands separator, compact suffixes or subscript zeros.
+///
+/// ```
+/// use uniswap_sdk_core::prelude::*;
+///
+/// let format = NumberFormat::new()
+///     .with_thousands_separator(',')
+///     .with_max_fraction_digits(2);
+/// let tvl = Fraction::new(123456789, 10);
+/// assert_eq!(tvl.to_formatted(&format).unwrap(), "12,345,678.9");
+///
+/// let format = NumberFormat::new()
+///     .with_subscript_zeros(4)
+///     .with_max_fraction_digits(4);
+/// let price = Fraction::new(1234, 1_000_000_000_000_i64);
+/// assert_eq!(price.to_formatted(&format).unwrap(), "0.0₈1234");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    /// Separator inserted between groups of three integer digits
+    pub thousands_separator: Option<char>,
+    /// Separator between the integer and fraction digits
+    pub decimal_separator: char,
+    /// Whether values of at least a thousand are abbreviated with a K, M, B or T suffix
+    pub compact: bool,
+    /// Minimum number of leading fraction zeros from which values below one are rendered in
+    /// subscript-zero notation, e.g. `0.0₈1234`. The digits following the zeros are then limited
+    /// to `max_fraction_digits` significant digits.
+    pub subscript_zeros: Option<u8>,
+    /// Whether trailing fraction zeros beyond `min_fraction_digits` are removed
+    pub trim_trailing_zeros: bool,
+    /// Minimum number of fraction digits
+    pub min_fraction_digits: u8,
+    /// Maximum number of fraction digits
+    pub max_fraction_digits: u8,
+    /// Rounding strategy applied to the last rendered digit
+    pub rounding: Rounding,
+}
+
+impl Default for NumberFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// Creates a [`NumberFormat`] with the default options
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            thousands_separator: None,
+            decimal_separator: '.',
+            compact: false,
+            subscript_zeros: None,
+            trim_trailing_zeros: true,
+            min_fraction_digits: 0,
+            max_fraction_digits: 6,
+            rounding: Rounding::RoundHalfUp,
+        }
+    }
+
+    /// Sets the thousands separator
+    #[inline]
+    #[must_use]
+    pub const fn with_thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Sets the decimal separator
+    #[inline]
+    #[must_use]
+    pub const fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Enables compact K/M/B/T suffixes
+    #[inline]
+    #[must_use]
+    pub const fn with_compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    /// Enables subscript-zero notation from the given number of leading fraction zeros
+    #[inline]
+    #[must_use]
+    pub const fn with_subscript_zeros(mut self, min_zeros: u8) -> Self {
+        self.subscript_zeros = Some(min_zeros);
+        self
+    }
+
+    /// Sets whether trailing fraction zeros are trimmed
+    #[inline]
+    #[must_use]
+    pub const fn with_trim_trailing_zeros(mut self, trim: bool) -> Self {
+        self.trim_trailing_zeros = trim;
+        self
+    }
+
+    /// Sets the minimum number of fraction digits
+    #[inline]
+    #[must_use]
+    pub const fn with_min_fraction_digits(mut self, digits: u8) -> Self {
+        self.min_fraction_digits = digits;
+        self
+    }
+
+    /// Sets the maximum number of fraction digits
+    #[inline]
+    #[must_use]
+    pub const fn with_max_fraction_digits(mut self, digits: u8) -> Self {
+        self.max_fraction_digits = digits;
+        self
+    }
+
+    /// Sets the rounding strategy
+    #[inline]
+    #[must_use]
+    pub const fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Writes a fraction into a [`fmt::Write`] buffer without allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min_fraction_digits` exceeds `max_fraction_digits`, if the fraction is
+    /// invalid, if the scaled value overflows [`BigInt`] or if the writer fails.
+    #[inline]
+    pub fn write(&self, w: &mut impl fmt::Write, value: &Fraction) -> Result<(), Error> {
+        if self.min_fraction_digits > self.max_fraction_digits {
+            return Err(Error::FractionDigitsRange {
+                min: self.min_fraction_digits,
+                max: self.max_fraction_digits,
+            });
+        }
+        let value = Fraction::try_new(value.numerator, value.denominator)?;
+        let negative = value.numerator.is_negative();
+        let magnitude = Fraction::try_new(
+            value
+                .numerator
+                .checked_abs()
+                .ok_or(Error::Overflow(BigInt::BITS))?,
+            value.denominator,
+        )?;
+        // the magnitude is rounded, so the directed modes swap sides for negative values
+        let rounding = match self.rounding {
+            Rounding::RoundFloor if negative => Rounding::RoundCeiling,
+            Rounding::RoundCeiling if negative => Rounding::RoundFloor,
+            rounding => rounding,
+        };
+
+        if let Some(min_zeros) = self.subscript_zeros
+            && let Some((zeros, digits)) = self.subscript_parts(&magnitude, min_zeros, rounding)?
+        {
+            if negative {
+                w.write_char('-')?;
+            }
+            w.write_char('0')?;
+            w.write_char(self.decimal_separator)?;
+            w.write_char('0')?;
+            let mut buf = StackBuffer::<DIGITS_CAPACITY>::new();
+            write!(buf, "{zeros}")?;
+            for digit in buf.bytes() {
+                w.write_char(SUBSCRIPT_DIGITS[(digit - b'0') as usize])?;
+            }
+            buf.clear();
+            write_big_uint(&mut buf, digits.unsigned_abs())?;
+            let digits = if self.trim_trailing_zeros {
+                buf.trim_end_matches('0')
+            } else {
+                buf.as_str()
+            };
+            w.write_str(digits)?;
+            return Ok(());
+        }
+
+        let (scaled, suffix) = self.scale(&magnitude, rounding)?;
+        let unit = pow10(self.max_fraction_digits as u32)?;
+        if negative && !scaled.is_zero() {
+            w.write_char('-')?;
+        }
+        self.write_integer(w, scaled / unit)?;
+        self.write_fraction(w, scaled % unit)?;
+        if let Some(suffix) = suffix {
+            w.write_char(suffix)?;
+        }
+        Ok(())
+    }
+
+    /// Formats a fraction into a [`String`]
+    ///
+    /// # Errors
+    ///
+    /// See [`NumberFormat::write`].
+    #[inline]
+    pub fn format(&self, value: &Fraction) -> Result<String, Error> {
+        let mut s = String::new();
+        self.write(&mut s, value)?;
+        Ok(s)
+    }
+
+    /// Rounds the magnitude to `max_fraction_digits` after dividing it by the compact tier, and
+    /// returns the result scaled by `10^max_fraction_digits` along with the compact suffix.
+    fn scale(
+        &self,
+        magnitude: &Fraction,
+        rounding: Rounding,
+    ) -> Result<(BigInt, Option<char>), Error> {
+        let unit = pow10(self.max_fraction_digits as u32)?;
+        let mut tier = 0;
+        if self.compact {
+            while tier < COMPACT_SUFFIXES.len()
+                && *magnitude >= Fraction::try_new(pow10(3 * (tier as u32 + 1))?, BigInt::ONE)?
+            {
+                tier += 1;
+            }
+        }
+        loop {
+            let scale = Fraction::try_new(unit, pow10(3 * tier as u32)?)?;
+            let scaled = magnitude.checked_mul(&scale)?.rounded_quotient(rounding);
+            // rounding may carry into the next tier, e.g. 999.9999K renders as 1M
+            if self.compact && tier < COMPACT_SUFFIXES.len() && scaled / unit >= BigInt::from(1000)
+            {
+                tier += 1;
+                continue;
+            }
+            return Ok((scaled, tier.checked_sub(1).map(|i| COMPACT_SUFFIXES[i])));
+        }
+    }
+
+    /// Returns the number of leading fraction zeros and the rounded significant digits following
+    /// them, or `None` if the magnitude should not be rendered in subscript-zero notation.
+    fn subscript_parts(
+        &self,
+        magnitude: &Fraction,
+        min_zeros: u8,
+        rounding: Rounding,
+    ) -> Result<Option<(u32, BigInt)>, Error> {
+        let Fraction {
+            numerator,
+            denominator,
+            ..
+        } = *magnitude;
+        if numerator.is_zero() || numerator >= denominator {
+            return Ok(None);
+        }
+        // the number of leading zeros is the largest `z` with `n * 10^(z + 1) < d`
+        let mut zeros = 0_u32;
+        let mut shifted = numerator;
+        while let Some(next) = shifted.checked_mul(BigInt::TEN) {
+            if next >= denominator {
+                break;
+            }
+            shifted = next;
+            zeros += 1;
+        }
+        let significant_digits = self.max_fraction_digits.max(1) as u32;
+        let unit = pow10(significant_digits)?;
+        let mut digits = Fraction::try_new(shifted, denominator)?
+            .checked_mul(&Fraction::try_new(unit, BigInt::ONE)?)?
+            .rounded_quotient(rounding);
+        // rounding may carry into the last zero, e.g. 0.000099999 renders as 0.0₄1, or into the
+        // integer part, e.g. 0.99999 renders as 1
+        if digits >= unit {
+            if zeros == 0 {
+                return Ok(None);
+            }
+            digits /= BigInt::TEN;
+            zeros -= 1;
+        }
+        if zeros < min_zeros as u32 {
+            return Ok(None);
+        }
+        Ok(Some((zeros, digits)))
+    }
+
+    /// Writes the integer digits, grouped by the thousands separator
+    fn write_integer(&self, w: &mut impl fmt::Write, integer: BigInt) -> Result<(), Error> {
+        let mut buf = StackBuffer::<DIGITS_CAPACITY>::new();
+        write_big_uint(&mut buf, integer.unsigned_abs())?;
+        let len = buf.len();
+        for (i, digit) in buf.chars().enumerate() {
+            if let Some(separator) = self.thousands_separator
+                && i > 0
+                && (len - i).is_multiple_of(3)
+            {
+                w.write_char(separator)?;
+            }
+            w.write_char(digit)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the fraction digits of a value below `10^max_fraction_digits`, including the
+    /// decimal separator if any digit is shown
+    fn write_fraction(&self, w: &mut impl fmt::Write, fraction: BigInt) -> Result<(), Error> {
+        let max = self.max_fraction_digits as usize;
+        if max == 0 {
+            return Ok(());
+        }
+        let mut buf = StackBuffer::<DIGITS_CAPACITY>::new();
+        write_big_uint(&mut buf, fraction.unsigned_abs())?;
+        let leading_zeros = max - buf.len();
+        let shown = if self.trim_trailing_zeros {
+            let significant = buf.trim_end_matches('0').len();
+            let needed = if significant == 0 {
+                0
+            } else {
+                leading_zeros + significant
+            };
+            needed.max(self.min_fraction_digits as usize)
+        } else {
+            max
+        };
+        if shown == 0 {
+            return Ok(());
+        }
+        w.write_char(self.decimal_separator)?;
+        let digits = buf.as_bytes();
+        for i in 0..shown {
+            let digit = if i < leading_zeros {
+                b'0'
+            } else {
+                digits[i - leading_zeros]
+            };
+            w.write_char(digit as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `10^exp`, or [`Error::Overflow`] if it does not fit in [`BigInt`]
+#[inline]
+fn pow10(exp: u32) -> Result<BigInt, Error> {
+    BigInt::TEN
+        .checked_pow(exp)
+        .ok_or(Error::Overflow(BigInt::BITS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(value: &Fraction, format: NumberFormat) -> String {
+        value.to_formatted(&format).unwrap()
+    }
+
+    #[test]
+    fn test_default() {
+        let f = NumberFormat::new();
+        assert_eq!(format(&Fraction::new(1, 3), f), "0.333333");
+        assert_eq!(format(&Fraction::new(5, 2), f), "2.5");
+        assert_eq!(format(&Fraction::new(1234567, 1), f), "1234567");
+        assert_eq!(format(&Fraction::new(-5, 2), f), "-2.5");
+        assert_eq!(format(&Fraction::new(-1, 10_000_000), f), "0");
+    }
+
+    #[test]
+    fn test_separators() {
+        let f = NumberFormat::new()
+            .with_thousands_separator('.')
+            .with_decimal_separator(',');
+        assert_eq!(format(&Fraction::new(123456789, 10), f), "12.345.678,9");
+        assert_eq!(format(&Fraction::new(123, 1), f), "123");
+        assert_eq!(format(&Fraction::new(-1234, 1), f), "-1.234");
+    }
+
+    #[test]
+    fn test_fraction_digits() {
+        let f = NumberFormat::new()
+            .with_min_fraction_digits(2)
+            .with_max_fraction_digits(4);
+        assert_eq!(format(&Fraction::new(3, 1), f), "3.00");
+        assert_eq!(format(&Fraction::new(31, 10), f), "3.10");
+        assert_eq!(format(&Fraction::new(31415926, 10_000_000), f), "3.1416");
+        assert_eq!(format(&Fraction::new(1, 1000), f), "0.001");
+        let f = f.with_trim_trailing_zeros(false);
+        assert_eq!(format(&Fraction::new(31, 10), f), "3.1000");
+        let f = f.with_rounding(Rounding::RoundDown);
+        assert_eq!(format(&Fraction::new(31415926, 10_000_000), f), "3.1415");
+        assert_eq!(
+            Fraction::new(1, 1).to_formatted(&NumberFormat::new().with_min_fraction_digits(7)),
+            Err(Error::FractionDigitsRange { min: 7, max: 6 })
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let f = NumberFormat::new()
+            .with_compact()
+            .with_max_fraction_digits(2);
+        assert_eq!(format(&Fraction::new(999, 1), f), "999");
+        assert_eq!(format(&Fraction::new(1500, 1), f), "1.5K");
+        assert_eq!(format(&Fraction::new(12_345_678, 1), f), "12.35M");
+        assert_eq!(format(&Fraction::new(999_999, 1), f), "1M");
+        assert_eq!(format(&Fraction::new(-2_000_000_000, 1), f), "-2B");
+        assert_eq!(
+            format(&Fraction::new(12_345_000_000_000_000_i64, 1), f),
+            "12345T"
+        );
+        let f = f.with_thousands_separator(',');
+        assert_eq!(
+            format(&Fraction::new(12_345_000_000_000_000_i64, 1), f),
+            "12,345T"
+        );
+    }
+
+    #[test]
+    fn test_subscript_zeros() {
+        let f = NumberFormat::new()
+            .with_subscript_zeros(4)
+            .with_max_fraction_digits(4);
+        assert_eq!(
+            format(&Fraction::new(1234, 1_000_000_000_000_i64), f),
+            "0.0₈1234"
+        );
+        assert_eq!(
+            format(&Fraction::new(-12345678, BigInt::TEN.pow(19)), f),
+            "-0.0₁₁1235"
+        );
+        assert_eq!(format(&Fraction::new(1, 2_000_000), f), "0.0₆5");
+        // rounding may carry into the zeros
+        assert_eq!(format(&Fraction::new(99999, 1_000_000_000), f), "0.0001");
+        let f = f.with_subscript_zeros(3);
+        assert_eq!(format(&Fraction::new(99999, 1_000_000_000), f), "0.0₃1");
+        // or into the integer part
+        assert_eq!(format(&Fraction::new(99999, 100_000), f), "1");
+        let f = f.with_subscript_zeros(0);
+        assert_eq!(format(&Fraction::new(99999, 100_000), f), "1");
+        assert_eq!(format(&Fraction::new(-99999, 100_000), f), "-1");
+        let f = f.with_subscript_zeros(3);
+        // below the threshold the regular notation is used
+        assert_eq!(format(&Fraction::new(12, 10_000), f), "0.0012");
+        assert_eq!(format(&Fraction::new(5, 2), f), "2.5");
+        assert_eq!(format(&Fraction::new(0, 1), f), "0");
+    }
+
+    #[test]
+    fn test_rounding_negative_values() {
+        let cases = [
+            (Rounding::RoundDown, "-0.66", "-0.0₅66", "-1.66K"),
+            (Rounding::RoundHalfUp, "-0.67", "-0.0₅67", "-1.67K"),
+            (Rounding::RoundUp, "-0.67", "-0.0₅67", "-1.67K"),
+            (Rounding::RoundHalfDown, "-0.67", "-0.0₅67", "-1.67K"),
+            (Rounding::RoundHalfEven, "-0.67", "-0.0₅67", "-1.67K"),
+            (Rounding::RoundCeiling, "-0.66", "-0.0₅66", "-1.66K"),
+            (Rounding::RoundFloor, "-0.67", "-0.0₅67", "-1.67K"),
+        ];
+        for (rounding, plain, subscript, compact) in cases {
+            let f = NumberFormat::new()
+                .with_max_fraction_digits(2)
+                .with_rounding(rounding);
+            let value = Fraction::new(-2, 3);
+            assert_eq!(format(&value, f), plain, "{rounding:?}");
+            assert_eq!(value.to_fixed(2, Some(rounding)), plain, "{rounding:?}");
+            let f = f.with_subscript_zeros(4);
+            assert_eq!(
+                format(&Fraction::new(-2, 300_000), f),
+                subscript,
+                "{rounding:?}"
+            );
+            let f = f.with_compact();
+            assert_eq!(format(&Fraction::new(-5000, 3), f), compact, "{rounding:?}");
+        }
+        // the positive counterparts round the other way
+        let f = NumberFormat::new().with_max_fraction_digits(2);
+        assert_eq!(
+            format(&Fraction::new(2, 3), f.with_rounding(Rounding::RoundFloor)),
+            "0.66"
+        );
+        assert_eq!(
+            format(
+                &Fraction::new(2, 3),
+                f.with_rounding(Rounding::RoundCeiling)
+            ),
+            "0.67"
+        );
+    }
+}
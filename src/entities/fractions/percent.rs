@@ -1,11 +1,10 @@
 use crate::prelude::*;
-use fastnum::i512;
+use crate::{entities::fractions::fraction::invalid_input, fraction};
+use core::{fmt, str::FromStr};
 
-const ONE_HUNDRED: Fraction = Fraction {
-    numerator: i512!(100),
-    denominator: BigInt::ONE,
-    meta: (),
-};
+const ONE_HUNDRED: Fraction = fraction!(100);
+
+const TEN_THOUSAND: Fraction = fraction!(10000);
 
 /// Unit struct to distinguish between a fraction and a percent
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
@@ -27,6 +26,47 @@ impl Percent {
         FractionBase::new(numerator, denominator, IsPercent)
     }
 
+    /// Writes the [`Percent`] with a specified number of significant digits and rounding strategy
+    /// into a [`fmt::Write`] buffer without allocating
+    #[inline]
+    pub fn write_significant(
+        &self,
+        w: &mut impl fmt::Write,
+        significant_digits: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        (self.as_fraction() * ONE_HUNDRED).write_significant(w, significant_digits, rounding)
+    }
+
+    /// Writes the [`Percent`] with a fixed number of decimal places and rounding strategy into a
+    /// [`fmt::Write`] buffer without allocating
+    #[inline]
+    pub fn write_fixed(
+        &self,
+        w: &mut impl fmt::Write,
+        decimal_places: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        (self.as_fraction() * ONE_HUNDRED).write_fixed(w, decimal_places, rounding)
+    }
+
+    /// Writes the [`Percent`] using a [`NumberFormat`] into a [`fmt::Write`] buffer without
+    /// allocating
+    #[inline]
+    pub fn write_formatted(
+        &self,
+        w: &mut impl fmt::Write,
+        format: &NumberFormat,
+    ) -> Result<(), Error> {
+        format.write(w, &(self.as_fraction() * ONE_HUNDRED))
+    }
+
+    /// Converts the [`Percent`] to a string using a [`NumberFormat`]
+    #[inline]
+    pub fn to_formatted(&self, format: &NumberFormat) -> Result<String, Error> {
+        format.format(&(self.as_fraction() * ONE_HUNDRED))
+    }
+
     /// Converts the [`Percent`] to a string with a specified number of significant digits and
     /// rounding strategy
     #[inline]
@@ -35,7 +75,9 @@ impl Percent {
         significant_digits: u8,
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
-        (self.as_fraction() * ONE_HUNDRED).to_significant(significant_digits, rounding)
+        let mut s = String::new();
+        self.write_significant(&mut s, significant_digits, rounding)?;
+        Ok(s)
     }
 
     /// Converts the [`Percent`] to a string with a fixed number of decimal places and rounding
@@ -43,13 +85,60 @@ impl Percent {
     #[inline]
     #[must_use]
     pub fn to_fixed(&self, decimal_places: u8, rounding: Option<Rounding>) -> String {
-        (self.as_fraction() * ONE_HUNDRED).to_fixed(decimal_places, rounding)
+        let mut s = String::new();
+        // writing into a `String` cannot fail
+        let _ = self.write_fixed(&mut s, decimal_places, rounding);
+        s
+    }
+}
+
+impl fmt::Display for Percent {
+    /// Formats the percent followed by a `%` sign, e.g. `"1.54%"` for `154 / 10000`.
+    ///
+    /// Precision flags behave as for [`Fraction`].
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = self.as_fraction() * ONE_HUNDRED;
+        fmt_decimal(
+            f,
+            percent.numerator,
+            percent.denominator,
+            0,
+            Rounding::default(),
+        )?;
+        f.write_str("%")
+    }
+}
+
+impl FromStr for Percent {
+    type Err = Error;
+
+    /// Parses a [`Percent`] from a string.
+    ///
+    /// Accepts a percentage with a `%` suffix (`"0.5%"`), basis points with a `bps` suffix
+    /// (`"50bps"`) or a plain ratio (`"0.005"`), where the number is anything accepted by
+    /// [`Fraction::from_decimal_str`]. All three examples parse to the same value.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse = |number: &str| {
+            Fraction::from_decimal_str(number).map_err(|error| invalid_input(error, s))
+        };
+        let fraction = if let Some(percent) = s.strip_suffix('%') {
+            parse(percent)?.checked_div(&ONE_HUNDRED)?
+        } else if let Some(bps) = s.strip_suffix("bps") {
+            parse(bps)?.checked_div(&TEN_THOUSAND)?
+        } else {
+            parse(s)?
+        };
+        Ok(Self::new(fraction.numerator, fraction.denominator))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
 
     #[test]
     fn test_add() {
@@ -107,6 +196,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_write_fixed() {
+        let mut buf = StackBuffer::<8>::new();
+        Percent::new(154, 10000)
+            .write_fixed(&mut buf, 3, None)
+            .unwrap();
+        assert_eq!(buf.as_str(), "1.540");
+    }
+
+    #[test]
+    fn test_display() {
+        let percent = Percent::new(154, 10000);
+        assert_eq!(format!("{percent}"), "1.54%");
+        assert_eq!(format!("{percent:.3}"), "1.540%");
+        assert_eq!(format!("{percent:#.2}"), "1.5%");
+        assert_eq!(format!("{}", Percent::new(1, 3)), "33.3333%");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let half_percent = Percent::new(5, 1000);
+        assert_eq!("0.5%".parse::<Percent>().unwrap(), half_percent);
+        assert_eq!("50bps".parse::<Percent>().unwrap(), half_percent);
+        assert_eq!("50 bps".parse::<Percent>().unwrap(), half_percent);
+        assert_eq!("0.005".parse::<Percent>().unwrap(), half_percent);
+        assert_eq!("5e-1 %".parse::<Percent>().unwrap(), half_percent);
+        assert_eq!("1/200".parse::<Percent>().unwrap(), half_percent);
+        assert_eq!("-1%".parse::<Percent>().unwrap(), Percent::new(-1, 100));
+        for s in ["%", "0.5 percent", "1/2/3bps"] {
+            assert_eq!(s.parse::<Percent>(), Err(Error::InvalidNumber(s.into())));
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        // a 5% rate compounded over three periods
+        let growth = (Percent::new(5, 100) + Percent::new(1, 1)).pow(3).unwrap();
+        assert_eq!(growth, Percent::new(9261, 8000));
+        assert_eq!(growth.to_fixed(4, None), "115.7625");
+        let root = growth.nth_root(3, 2, Rounding::RoundHalfUp).unwrap();
+        assert_eq!(root.to_fixed(2, None), "105.00");
+    }
+
     #[test]
     fn test_to_fixed() {
         assert_eq!(
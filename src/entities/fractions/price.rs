@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use core::fmt;
 use fastnum::i512;
 
 /// Type alias for a Price, a [`FractionLike`] with metadata [`PriceMeta`]
@@ -27,6 +28,12 @@ where
     TQuote: BaseCurrency,
 {
     /// Constructor for creating a new [`Price`] instance
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero, or if it is negative and the sign of the numerator or
+    /// denominator cannot be flipped without overflow, e.g. for [`BigInt::MIN`]. Use
+    /// [`Price::try_new`] to handle these cases.
     #[inline]
     pub fn new(
         base_currency: TBase,
@@ -34,12 +41,29 @@ where
         denominator: impl Into<BigInt>,
         numerator: impl Into<BigInt>,
     ) -> Self {
+        Self::try_new(base_currency, quote_currency, denominator, numerator)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Fallible constructor for creating a new [`Price`] instance
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroDenominator`] if the denominator is zero and [`Error::Overflow`] if
+    /// normalizing the sign of a negative denominator overflows.
+    #[inline]
+    pub fn try_new(
+        base_currency: TBase,
+        quote_currency: TQuote,
+        denominator: impl Into<BigInt>,
+        numerator: impl Into<BigInt>,
+    ) -> Result<Self, Error> {
         // Calculate scalar based on decimal places of base and quote currencies
         let scalar = Fraction::new(
             i512!(10).pow(base_currency.decimals() as u32),
             i512!(10).pow(quote_currency.decimals() as u32),
         );
-        FractionBase::new(
+        FractionBase::try_new(
             numerator,
             denominator,
             PriceMeta {
@@ -51,13 +75,20 @@ where
     }
 
     /// Create a [`Price`] instance from currency amounts of the base and quote currencies
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroDenominator`] if the base amount is zero and [`Error::Overflow`] if
+    /// the quotient of the amounts overflows.
     #[inline]
     pub fn from_currency_amounts(
         base_amount: CurrencyAmount<TBase>,
         quote_amount: CurrencyAmount<TQuote>,
-    ) -> Self {
-        let res = quote_amount.divide(&base_amount).unwrap();
-        Self::new(
+    ) -> Result<Self, Error> {
+        let res = quote_amount
+            .as_fraction()
+            .checked_div(&base_amount.as_fraction())?;
+        Self::try_new(
             base_amount.meta.currency,
             quote_amount.meta.currency,
             res.denominator,
@@ -84,9 +115,12 @@ where
         other: &Price<TQuote, TOtherQuote>,
     ) -> Result<Price<TBase, TOtherQuote>, Error> {
         if !self.quote_currency.equals(&other.base_currency) {
-            return Err(Error::CurrencyMismatch);
+            return Err(Error::currency_mismatch(
+                &self.quote_currency,
+                &other.base_currency,
+            ));
         }
-        let fraction = self.as_fraction() * other.as_fraction();
+        let fraction = self.as_fraction().checked_mul(&other.as_fraction())?;
         Ok(Price::new(
             self.base_currency.clone(),
             other.quote_currency.clone(),
@@ -102,9 +136,14 @@ where
         currency_amount: &CurrencyAmount<TBase>,
     ) -> Result<CurrencyAmount<TQuote>, Error> {
         if !currency_amount.currency.equals(&self.base_currency) {
-            return Err(Error::CurrencyMismatch);
+            return Err(Error::currency_mismatch(
+                &self.base_currency,
+                &currency_amount.currency,
+            ));
         }
-        let fraction = self.as_fraction() * currency_amount.as_fraction();
+        let fraction = self
+            .as_fraction()
+            .checked_mul(&currency_amount.as_fraction())?;
         CurrencyAmount::from_fractional_amount(
             self.quote_currency.clone(),
             fraction.numerator,
@@ -118,6 +157,49 @@ where
         self.as_fraction() * &self.scalar
     }
 
+    /// Writes the adjusted price with a specified number of significant digits and rounding
+    /// strategy into a [`fmt::Write`] buffer without allocating
+    #[inline]
+    pub fn write_significant(
+        &self,
+        w: &mut impl fmt::Write,
+        significant_digits: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        self.adjusted_for_decimals()
+            .write_significant(w, significant_digits, rounding)
+    }
+
+    /// Writes the adjusted price with a fixed number of decimal places and rounding strategy into
+    /// a [`fmt::Write`] buffer without allocating
+    #[inline]
+    pub fn write_fixed(
+        &self,
+        w: &mut impl fmt::Write,
+        decimal_places: u8,
+        rounding: Option<Rounding>,
+    ) -> Result<(), Error> {
+        self.adjusted_for_decimals()
+            .write_fixed(w, decimal_places, rounding)
+    }
+
+    /// Writes the adjusted price using a [`NumberFormat`] into a [`fmt::Write`] buffer without
+    /// allocating
+    #[inline]
+    pub fn write_formatted(
+        &self,
+        w: &mut impl fmt::Write,
+        format: &NumberFormat,
+    ) -> Result<(), Error> {
+        format.write(w, &self.adjusted_for_decimals())
+    }
+
+    /// Converts the adjusted price to a string using a [`NumberFormat`]
+    #[inline]
+    pub fn to_formatted(&self, format: &NumberFormat) -> Result<String, Error> {
+        format.format(&self.adjusted_for_decimals())
+    }
+
     /// Converts the adjusted price to a string with a specified number of significant digits and
     /// rounding strategy
     #[inline]
@@ -126,16 +208,49 @@ where
         significant_digits: u8,
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
-        self.adjusted_for_decimals()
-            .to_significant(significant_digits, rounding)
+        let mut s = String::new();
+        self.write_significant(&mut s, significant_digits, rounding)?;
+        Ok(s)
     }
 
     /// Converts the adjusted price to a string with a fixed number of decimal places and rounding
     /// strategy
     #[inline]
     pub fn to_fixed(&self, decimal_places: u8, rounding: Option<Rounding>) -> String {
-        self.adjusted_for_decimals()
-            .to_fixed(decimal_places, rounding)
+        let mut s = String::new();
+        // writing into a `String` cannot fail
+        let _ = self.write_fixed(&mut s, decimal_places, rounding);
+        s
+    }
+}
+
+impl<TBase, TQuote> fmt::Display for Price<TBase, TQuote>
+where
+    TBase: BaseCurrency,
+    TQuote: BaseCurrency,
+{
+    /// Formats the adjusted price as `"1 WETH = 3000.12 USDC"`, omitting missing symbols.
+    ///
+    /// Precision flags apply to the quote amount and behave as for [`Fraction`].
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("1")?;
+        if let Some(symbol) = self.base_currency.symbol() {
+            write!(f, " {symbol}")?;
+        }
+        f.write_str(" = ")?;
+        let adjusted = self.adjusted_for_decimals();
+        fmt_decimal(
+            f,
+            adjusted.numerator,
+            adjusted.denominator,
+            0,
+            Rounding::default(),
+        )?;
+        if let Some(symbol) = self.quote_currency.symbol() {
+            write!(f, " {symbol}")?;
+        }
+        Ok(())
     }
 }
 
@@ -143,6 +258,7 @@ where
 mod test {
     use super::*;
     use crate::token;
+    use alloc::format;
     use lazy_static::lazy_static;
 
     const ADDRESS_ZERO: &str = "0x0000000000000000000000000000000000000000";
@@ -170,11 +286,25 @@ mod test {
             let price = Price::from_currency_amounts(
                 CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1).unwrap(),
                 CurrencyAmount::from_raw_amount(TOKEN1.clone(), 54321).unwrap(),
-            );
+            )
+            .unwrap();
             assert_eq!(price.to_significant(5, None).unwrap(), "54321");
             assert!(price.base_currency.equals(&TOKEN0.clone()));
             assert!(price.quote_currency.equals(&TOKEN1.clone()));
         }
+
+        #[test]
+        fn zero_base_amount_is_an_error() {
+            let price = Price::from_currency_amounts(
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 0).unwrap(),
+                CurrencyAmount::from_raw_amount(TOKEN1.clone(), 54321).unwrap(),
+            );
+            assert_eq!(price, Err(Error::ZeroDenominator));
+            assert_eq!(
+                Price::try_new(TOKEN0.clone(), TOKEN1.clone(), 0, 1),
+                Err(Error::ZeroDenominator)
+            );
+        }
     }
 
     #[test]
@@ -188,6 +318,60 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_write_significant() {
+        let price = Price::new(TOKEN0_6.clone(), TOKEN1.clone(), 123, 456);
+        let mut buf = StackBuffer::<16>::new();
+        price.write_significant(&mut buf, 4, None).unwrap();
+        assert_eq!(buf.as_str(), "3.707E-12");
+    }
+
+    #[test]
+    fn test_sqrt_and_log_keep_meta() {
+        let price = Price::new(TOKEN0_6.clone(), TOKEN1.clone(), 1, 4_000_000);
+        let root = price.sqrt(0, Rounding::RoundDown).unwrap();
+        assert!(root.base_currency.equals(&TOKEN0_6.clone()));
+        assert!(root.quote_currency.equals(&TOKEN1.clone()));
+        assert_eq!(root.scalar, price.scalar);
+        assert_eq!(root.as_fraction(), Fraction::new(2000, 1));
+        let tick = price.log_base(&Fraction::new(10001, 10000), 0).unwrap();
+        assert_eq!(tick.as_fraction(), Fraction::new(152_026, 1));
+        assert_eq!(tick.scalar, price.scalar);
+    }
+
+    #[test]
+    fn test_to_formatted() {
+        let meme = token!(1, ADDRESS_ZERO, 18, "MEME");
+        let usdc = token!(1, ADDRESS_ONE, 6, "USDC");
+        // 1 MEME = 0.000000001234 USDC
+        let price = Price::new(meme, usdc, i512!(10).pow(24), 1234);
+        let format = NumberFormat::new()
+            .with_subscript_zeros(4)
+            .with_max_fraction_digits(4);
+        assert_eq!(price.to_formatted(&format).unwrap(), "0.0₈1234");
+        assert_eq!(
+            price.invert().to_formatted(&format).unwrap(),
+            "810372771.4749"
+        );
+        let format = format.with_compact().with_max_fraction_digits(2);
+        assert_eq!(price.invert().to_formatted(&format).unwrap(), "810.37M");
+    }
+
+    #[test]
+    fn test_display() {
+        let weth = token!(1, ADDRESS_ZERO, 18, "WETH");
+        let usdc = token!(1, ADDRESS_ONE, 6, "USDC");
+        // 1 WETH = 3000.123456 USDC
+        let price = Price::new(weth, usdc, i512!(10).pow(18), 3000123456_u32);
+        assert_eq!(format!("{price:.2}"), "1 WETH = 3000.12 USDC");
+        assert_eq!(format!("{price}"), "1 WETH = 3000.12 USDC");
+        assert_eq!(format!("{price:#.8}"), "1 WETH = 3000.1235 USDC");
+        assert_eq!(
+            format!("{}", Price::new(TOKEN0.clone(), TOKEN1.clone(), 123, 456)),
+            "1 = 3.70732"
+        );
+    }
+
     mod to_significant {
         use super::*;
 
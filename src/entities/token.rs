@@ -20,12 +20,14 @@ macro_rules! impl_base_currency {
             impl BaseCurrency for $token {
                 #[inline]
                 fn equals(&self, other: &impl BaseCurrency) -> bool {
-                    other.is_token() && self.chain_id == other.chain_id() && self.address == other.address()
+                    other.is_token()
+                        && self.chain_id == other.chain_id()
+                        && other.address().is_ok_and(|address| address == self.address)
                 }
 
                 #[inline]
-                fn wrapped(&self) -> &Token {
-                    self
+                fn wrapped(&self) -> Result<&Token, Error> {
+                    Ok(self)
                 }
             }
         )*
@@ -79,8 +81,37 @@ impl Token {
         }
     }
 
+    /// Creates a new [`Token`], returning [`Error::ZeroChainId`] instead of panicking if
+    /// `chain_id` is 0.
+    ///
+    /// See [`Token::new`] for the arguments.
+    #[inline]
+    pub fn try_new(
+        chain_id: u64,
+        address: Address,
+        decimals: u8,
+        symbol: Option<String>,
+        name: Option<String>,
+        buy_fee_bps: u64,
+        sell_fee_bps: u64,
+    ) -> Result<Self, Error> {
+        if chain_id == 0 {
+            return Err(Error::ZeroChainId);
+        }
+        Ok(Self::new(
+            chain_id,
+            address,
+            decimals,
+            symbol,
+            name,
+            buy_fee_bps,
+            sell_fee_bps,
+        ))
+    }
+
     /// Returns true if the address of this token sorts before the address of the other token.
-    /// Panics if the tokens have the same address or if the tokens are on different chains.
+    /// Returns an error if the tokens have the same address or if the tokens are on different
+    /// chains.
     ///
     /// # Arguments
     ///
@@ -91,10 +122,10 @@ impl Token {
             return Err(Error::ChainIdMismatch(self.chain_id, other.chain_id));
         }
 
-        if self.address() == other.address() {
-            return Err(Error::EqualAddresses);
+        if self.address == other.address {
+            return Err(Error::EqualAddresses(self.address));
         }
-        Ok(self.address() < other.address())
+        Ok(self.address < other.address)
     }
 }
 
@@ -256,4 +287,30 @@ mod tests {
             assert!(token_a.equals(&token_b));
         }
     }
+
+    #[test]
+    fn try_new_rejects_zero_chain_id() {
+        let address = token!(1, ADDRESS_ONE, 18).address;
+        assert_eq!(
+            Token::try_new(0, address, 18, None, None, 0, 0),
+            Err(Error::ZeroChainId)
+        );
+        assert_eq!(
+            Token::try_new(1, address, 18, None, None, 0, 0),
+            Ok(token!(1, ADDRESS_ONE, 18))
+        );
+    }
+
+    #[test]
+    fn sorts_before_errors_carry_context() {
+        let token = token!(1, ADDRESS_ONE, 18);
+        assert_eq!(
+            token.sorts_before(&token!(1, ADDRESS_ONE, 6)),
+            Err(Error::EqualAddresses(token.address))
+        );
+        assert_eq!(
+            token.sorts_before(&token!(10, ADDRESS_TWO, 18)),
+            Err(Error::ChainIdMismatch(1, 10))
+        );
+    }
 }
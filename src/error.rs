@@ -1,34 +1,347 @@
+use crate::prelude::{Address, BaseCurrency, Fraction, FractionBase, Percent};
+use alloc::{boxed::Box, string::String};
+use core::fmt;
+
 /// Custom error types that are used throughout the SDK to handle various error conditions.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, thiserror::Error)]
+///
+/// Every variant has a stable, machine-readable [`code`](Error::code) that does not change when
+/// the human-readable message does.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     /// Triggers when the compared chain IDs do not match.
     #[error("chain IDs do not match: {0} and {1}")]
     ChainIdMismatch(u64, u64),
 
     /// Triggers when compared addresses are the same.
-    #[error("addresses are equal")]
-    EqualAddresses,
+    #[error("addresses are equal: {0}")]
+    EqualAddresses(Address),
+
+    /// Triggers when a value exceeds [`alloy_primitives::U256::MAX`].
+    #[error("{} exceeds U256::MAX", Exact(.0))]
+    UintOverflow(Box<Fraction>),
+
+    /// Triggers when a fraction would be constructed with a zero denominator.
+    #[error("denominator is zero")]
+    ZeroDenominator,
 
-    /// Triggers when it tries to exceed [`alloy_primitives::U256::MAX`].
-    #[error("amount exceeds U256::MAX")]
-    UintOverflow,
+    /// Triggers when a result does not fit in an integer type of the given number of bits, e.g.
+    /// when fraction arithmetic overflows the backing integer type.
+    #[error("arithmetic overflow of a {0}-bit integer")]
+    Overflow(u32),
 
-    /// Triggers when the currency values are not equal.
-    #[error("currency values are not equal")]
-    CurrencyMismatch,
+    /// Triggers when an operation combines values of different currencies.
+    #[error("currency mismatch: expected {expected}, found {found}")]
+    CurrencyMismatch {
+        /// The currency the operation requires
+        expected: CurrencyId,
+        /// The currency that was supplied
+        found: CurrencyId,
+    },
 
-    /// Triggers when the value is invalid.
-    #[error("{0}")]
-    Invalid(&'static str),
+    /// Triggers when writing formatted output into a buffer fails, e.g. because it is full.
+    #[error("failed to write formatted output")]
+    Fmt,
 
     /// Triggers when a chain has no registered metadata for an operation.
     #[error("unsupported chain ID: {0}")]
     UnsupportedChain(u64),
+
+    /// Triggers when a currency would be created with a chain ID of zero.
+    #[error("chain ID is zero")]
+    ZeroChainId,
+
+    /// Triggers when a native currency has no wrapped token on its chain.
+    #[error("no wrapped token for the native currency on chain {0}")]
+    NoWrappedToken(u64),
+
+    /// Triggers when a string is not a valid hex encoded address.
+    #[error("invalid address: {0}")]
+    InvalidAddress(String),
+
+    /// Triggers when more decimal places are requested than the currency has.
+    #[error("{decimal_places} decimal places exceed the {decimals} decimals of the currency")]
+    ExcessDecimalPlaces {
+        /// The requested number of decimal places
+        decimal_places: u8,
+        /// The decimals of the currency
+        decimals: u8,
+    },
+
+    /// Triggers when a parsed amount has more fraction digits than the currency has decimals.
+    #[error("amount has more than {decimals} decimal places")]
+    ExcessPrecision {
+        /// The decimals of the currency
+        decimals: u8,
+    },
+
+    /// Triggers when a token transfer fee is not below 100%.
+    #[error("transfer fee of {0} bps is not below 100%")]
+    InvalidFeeBps(u64),
+
+    /// Triggers when a slippage tolerance is negative or exceeds 100%.
+    #[error("slippage tolerance of {0} is not between 0% and 100%")]
+    InvalidSlippage(Box<Percent>),
+
+    /// Triggers when zero significant digits are requested.
+    #[error("significant digits must be positive")]
+    ZeroSignificantDigits,
+
+    /// Triggers when a number format requires more fraction digits than it allows.
+    #[error("minimum fraction digits {min} exceed maximum fraction digits {max}")]
+    FractionDigitsRange {
+        /// The minimum number of fraction digits
+        min: u8,
+        /// The maximum number of fraction digits
+        max: u8,
+    },
+
+    /// Triggers when a string is not a valid decimal number.
+    #[error("invalid number: {0:?}")]
+    InvalidNumber(String),
+
+    /// Triggers when a floating point number is infinite or NaN.
+    #[error("number is not finite")]
+    NotFinite,
+
+    /// Triggers when the maximum denominator of an approximation is less than one.
+    #[error("maximum denominator must be positive")]
+    NonPositiveMaxDenominator,
+
+    /// Triggers when a decimal expansion has more fraction digits than allowed.
+    #[error("decimal expansion exceeds {0} digits")]
+    MaxDigitsExceeded(usize),
+
+    /// Triggers when a value must be rendered exactly but its decimal expansion does not
+    /// terminate.
+    #[error("decimal expansion does not terminate")]
+    NonTerminating,
+
+    /// Triggers when a fixed-point number would have more fraction bits than supported.
+    #[error("{bits} fraction bits exceed the maximum of {max}")]
+    FractionalBitsExceeded {
+        /// The requested number of fraction bits
+        bits: u32,
+        /// The maximum number of fraction bits
+        max: u32,
+    },
+
+    /// Triggers when an operation requires a non-negative value.
+    #[error("{} is negative", Exact(.0))]
+    Negative(Box<Fraction>),
+
+    /// Triggers when an operation requires a positive value.
+    #[error("{} is not positive", Exact(.0))]
+    NotPositive(Box<Fraction>),
+
+    /// Triggers when a value with a fractional part is converted to an integer type.
+    #[error("{} is not a whole number", Exact(.0))]
+    NotInteger(Box<Fraction>),
+
+    /// Triggers when the zeroth root is requested.
+    #[error("root degree is zero")]
+    ZeroRoot,
+
+    /// Triggers when a logarithm is taken in base one.
+    #[error("logarithm base is one")]
+    LogBaseOne,
+
+    /// Triggers when the lower bound of an interval exceeds its upper bound.
+    #[error("interval lower bound exceeds its upper bound")]
+    InvalidInterval,
+
+    /// Triggers when a sorted list would be limited to zero items or already exceeds its limit.
+    #[error("{len} items do not fit a maximum size of {max_size}")]
+    InvalidMaxSize {
+        /// The number of items in the list
+        len: usize,
+        /// The maximum size of the list
+        max_size: usize,
+    },
+
+    /// Triggers when encoded input ends in the middle of a value.
+    #[error("unexpected end of input")]
+    UnexpectedEof,
+
+    /// Triggers when encoded input contains a malformed varint.
+    #[error("invalid varint")]
+    InvalidVarint,
+
+    /// Triggers when encoded input contains a non-canonical integer.
+    #[error("non-canonical integer encoding")]
+    NonCanonical,
+
+    /// Triggers when encoded input continues after the decoded value.
+    #[error("{0} trailing bytes")]
+    TrailingBytes(usize),
+
+    /// Triggers when an encoded string is not valid UTF-8.
+    #[error("invalid UTF-8")]
+    InvalidUtf8,
+
+    /// Triggers when an encoded currency reference does not resolve to a known token.
+    #[error("unknown currency {address} on chain {chain_id}")]
+    UnknownCurrency {
+        /// The chain ID of the reference
+        chain_id: u64,
+        /// The address of the reference
+        address: Address,
+    },
+}
+
+impl Error {
+    /// Returns the stable error code of the variant, e.g. `"ZERO_DENOMINATOR"`
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::ChainIdMismatch(..) => "CHAIN_ID_MISMATCH",
+            Self::EqualAddresses(_) => "EQUAL_ADDRESSES",
+            Self::UintOverflow(_) => "UINT_OVERFLOW",
+            Self::ZeroDenominator => "ZERO_DENOMINATOR",
+            Self::Overflow(_) => "OVERFLOW",
+            Self::CurrencyMismatch { .. } => "CURRENCY_MISMATCH",
+            Self::Fmt => "FMT",
+            Self::UnsupportedChain(_) => "UNSUPPORTED_CHAIN",
+            Self::ZeroChainId => "ZERO_CHAIN_ID",
+            Self::NoWrappedToken(_) => "NO_WRAPPED_TOKEN",
+            Self::InvalidAddress(_) => "INVALID_ADDRESS",
+            Self::ExcessDecimalPlaces { .. } => "EXCESS_DECIMAL_PLACES",
+            Self::ExcessPrecision { .. } => "EXCESS_PRECISION",
+            Self::InvalidFeeBps(_) => "INVALID_FEE_BPS",
+            Self::InvalidSlippage(_) => "INVALID_SLIPPAGE",
+            Self::ZeroSignificantDigits => "ZERO_SIGNIFICANT_DIGITS",
+            Self::FractionDigitsRange { .. } => "FRACTION_DIGITS_RANGE",
+            Self::InvalidNumber(_) => "INVALID_NUMBER",
+            Self::NotFinite => "NOT_FINITE",
+            Self::NonPositiveMaxDenominator => "NON_POSITIVE_MAX_DENOMINATOR",
+            Self::MaxDigitsExceeded(_) => "MAX_DIGITS_EXCEEDED",
+            Self::NonTerminating => "NON_TERMINATING",
+            Self::FractionalBitsExceeded { .. } => "FRACTIONAL_BITS_EXCEEDED",
+            Self::Negative(_) => "NEGATIVE",
+            Self::NotPositive(_) => "NOT_POSITIVE",
+            Self::NotInteger(_) => "NOT_INTEGER",
+            Self::ZeroRoot => "ZERO_ROOT",
+            Self::LogBaseOne => "LOG_BASE_ONE",
+            Self::InvalidInterval => "INVALID_INTERVAL",
+            Self::InvalidMaxSize { .. } => "INVALID_MAX_SIZE",
+            Self::UnexpectedEof => "UNEXPECTED_EOF",
+            Self::InvalidVarint => "INVALID_VARINT",
+            Self::NonCanonical => "NON_CANONICAL",
+            Self::TrailingBytes(_) => "TRAILING_BYTES",
+            Self::InvalidUtf8 => "INVALID_UTF8",
+            Self::UnknownCurrency { .. } => "UNKNOWN_CURRENCY",
+        }
+    }
+
+    /// Returns a [`Error::CurrencyMismatch`] between two currencies
+    #[inline]
+    #[must_use]
+    pub fn currency_mismatch(expected: &impl BaseCurrency, found: &impl BaseCurrency) -> Self {
+        Self::CurrencyMismatch {
+            expected: CurrencyId::of(expected),
+            found: CurrencyId::of(found),
+        }
+    }
+
+    /// Returns an [`Error::UintOverflow`] for a value
+    #[inline]
+    #[must_use]
+    pub fn uint_overflow(value: Fraction) -> Self {
+        Self::UintOverflow(Box::new(value))
+    }
+
+    /// Returns an [`Error::Negative`] for a value
+    #[inline]
+    #[must_use]
+    pub fn negative(value: Fraction) -> Self {
+        Self::Negative(Box::new(value))
+    }
+
+    /// Returns an [`Error::NotPositive`] for a value
+    #[inline]
+    #[must_use]
+    pub fn not_positive(value: Fraction) -> Self {
+        Self::NotPositive(Box::new(value))
+    }
+
+    /// Returns an [`Error::NotInteger`] for a value
+    #[inline]
+    #[must_use]
+    pub fn not_integer(value: Fraction) -> Self {
+        Self::NotInteger(Box::new(value))
+    }
+
+    /// Returns an [`Error::InvalidSlippage`] for a slippage tolerance
+    #[inline]
+    #[must_use]
+    pub fn invalid_slippage(slippage: Percent) -> Self {
+        Self::InvalidSlippage(Box::new(slippage))
+    }
+}
+
+impl From<fmt::Error> for Error {
+    #[inline]
+    fn from(_: fmt::Error) -> Self {
+        Self::Fmt
+    }
+}
+
+/// Displays the value carried by an error exactly, as `numerator` or `numerator/denominator`
+struct Exact<'a>(&'a Fraction);
+
+impl fmt::Display for Exact<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.0.reduced();
+        if value.denominator.is_one() {
+            write!(f, "{}", value.numerator)
+        } else {
+            write!(f, "{}/{}", value.numerator, value.denominator)
+        }
+    }
+}
+
+/// Identifies a currency in error context by its chain ID and, for tokens, its address
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CurrencyId {
+    /// The chain ID of the currency
+    pub chain_id: u64,
+    /// The address of the currency, `None` for a native currency
+    pub address: Option<Address>,
+}
+
+impl CurrencyId {
+    /// Returns the identity of a currency
+    #[inline]
+    #[must_use]
+    pub fn of(currency: &impl BaseCurrency) -> Self {
+        Self {
+            chain_id: currency.chain_id(),
+            address: currency
+                .is_token()
+                .then(|| currency.address().ok())
+                .flatten(),
+        }
+    }
+}
+
+impl fmt::Display for CurrencyId {
+    /// Formats the currency as `<address>@<chain_id>`, or `native@<chain_id>`
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.address {
+            Some(address) => write!(f, "{address}@{}", self.chain_id),
+            None => write!(f, "native@{}", self.chain_id),
+        }
+    }
 }
 
 #[cfg(all(feature = "std", test))]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use alloy_primitives::address;
 
     #[test]
     fn test_chain_id_mismatch_error() {
@@ -38,25 +351,96 @@ mod tests {
 
     #[test]
     fn test_equal_addresses_error() {
-        let error = Error::EqualAddresses;
-        assert_eq!(error.to_string(), "addresses are equal");
+        let error = Error::EqualAddresses(Address::ZERO);
+        assert_eq!(
+            error.to_string(),
+            "addresses are equal: 0x0000000000000000000000000000000000000000"
+        );
     }
 
     #[test]
     fn test_max_uint_error() {
-        let error = Error::UintOverflow;
-        assert_eq!(error.to_string(), "amount exceeds U256::MAX");
+        let error = Error::uint_overflow(Fraction::new(MAX_UINT256 + BigInt::ONE, 1));
+        assert_eq!(
+            error.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936 \
+             exceeds U256::MAX"
+        );
+        assert_eq!(error.code(), "UINT_OVERFLOW");
+    }
+
+    #[test]
+    fn test_zero_denominator_error() {
+        let error = Error::ZeroDenominator;
+        assert_eq!(error.to_string(), "denominator is zero");
+    }
+
+    #[test]
+    fn test_overflow_error() {
+        let error = Error::Overflow(BigInt::BITS);
+        assert_eq!(
+            error.to_string(),
+            "arithmetic overflow of a 512-bit integer"
+        );
     }
 
     #[test]
-    fn test_not_equal_error() {
-        let error = Error::CurrencyMismatch;
-        assert_eq!(error.to_string(), "currency values are not equal");
+    fn test_currency_mismatch_error() {
+        let token = Token::new(
+            1,
+            address!("0x6B175474E89094C44Da98b954EedeAC495271d0F"),
+            18,
+            None,
+            None,
+            0,
+            0,
+        );
+        let error = Error::currency_mismatch(&token, &Ether::on_chain(1));
+        assert_eq!(
+            error.to_string(),
+            "currency mismatch: expected 0x6B175474E89094C44Da98b954EedeAC495271d0F@1, found \
+             native@1"
+        );
+        assert_eq!(error.code(), "CURRENCY_MISMATCH");
     }
 
     #[test]
-    fn test_incorrect_error() {
-        let error = Error::Invalid("invalid");
-        assert_eq!(error.to_string(), "invalid");
+    fn test_contextual_errors() {
+        let error = Error::ExcessDecimalPlaces {
+            decimal_places: 8,
+            decimals: 6,
+        };
+        assert_eq!(
+            error.to_string(),
+            "8 decimal places exceed the 6 decimals of the currency"
+        );
+        assert_eq!(error.code(), "EXCESS_DECIMAL_PLACES");
+        let error = Error::FractionalBitsExceeded {
+            bits: 300,
+            max: 256,
+        };
+        assert_eq!(
+            error.to_string(),
+            "300 fraction bits exceed the maximum of 256"
+        );
+        assert_eq!(Error::NoWrappedToken(5042).code(), "NO_WRAPPED_TOKEN");
+        let error = Error::InvalidAddress("0x1234".into());
+        assert_eq!(error.to_string(), "invalid address: 0x1234");
+        assert_eq!(error.code(), "INVALID_ADDRESS");
+        let error = Error::InvalidNumber("1.2.3".into());
+        assert_eq!(error.to_string(), "invalid number: \"1.2.3\"");
+        let error = Error::negative(Fraction::new(-2, 4));
+        assert_eq!(error.to_string(), "-1/2 is negative");
+        assert_eq!(error.code(), "NEGATIVE");
+        let error = Error::not_positive(Fraction::ZERO);
+        assert_eq!(error.to_string(), "0 is not positive");
+        let error = Error::not_integer(Fraction::new(7, 2));
+        assert_eq!(error.to_string(), "7/2 is not a whole number");
+        let error = Error::invalid_slippage(Percent::new(150, 100));
+        assert_eq!(
+            error.to_string(),
+            "slippage tolerance of 150% is not between 0% and 100%"
+        );
+        assert_eq!(error.code(), "INVALID_SLIPPAGE");
     }
 }
@@ -24,6 +24,51 @@ use num_traits as _;
 pub mod addresses;
 /// Contains functionality related to All Contracts deployed and supported by the Uniswap SDK.
 pub mod chains;
+/// Compact binary encoding for tokens, fractions, prices and currency amounts, enabled by the
+/// `codec` feature.
+///
+/// The format is built from the following primitives:
+///
+/// - Varints are unsigned LEB128 without redundant trailing zero bytes.
+/// - A big integer is a varint header `len << 1 | sign` followed by the `len` big-endian bytes of
+///   its magnitude without leading zeros, so zero is the single byte `0x00` and a `MAX_UINT256`
+///   numerator takes 33 bytes.
+/// - An optional string is a varint of its byte length plus one, or `0x00` for `None`, followed by
+///   its UTF-8 bytes.
+/// - A currency reference is the chain ID as a varint followed by the 20 address bytes.
+///
+/// Values are encoded as the concatenation of their fields:
+///
+/// - [`Token`](entities::Token): currency reference, decimals byte, optional symbol, optional
+///   name, buy fee varint and sell fee varint.
+/// - [`Fraction`](entities::Fraction), [`Percent`](entities::Percent) and any other
+///   [`FractionLike`](entities::FractionLike): numerator and denominator.
+/// - [`CurrencyAmount<Token>`](entities::CurrencyAmount): currency reference, numerator and
+///   denominator, written by `encode_with_currency`.
+/// - [`Price<Token, Token>`](entities::Price): base and quote currency references, numerator and
+///   denominator, written by `encode_with_currencies`.
+///
+/// Currency references are resolved through a [`TokenResolver`](codec::TokenResolver) when
+/// decoding, and derived values such as the price scalar are recomputed. Decoding rejects
+/// non-canonical integers, zero denominators, a zero chain ID, unknown currencies and currency
+/// amounts exceeding `MAX_UINT256`.
+///
+/// ```
+/// use uniswap_sdk_core::{codec::*, prelude::*, token};
+///
+/// let usdc = token!(1, "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", 6, "USDC");
+/// let amount = CurrencyAmount::from_raw_amount(usdc.clone(), 1_000_000).unwrap();
+/// let mut bytes = Vec::new();
+/// amount.encode_with_currency(&mut bytes);
+/// assert_eq!(bytes.len(), 27);
+///
+/// let tokens = [usdc];
+/// let decoded = CurrencyAmount::from_bytes(&bytes, tokens.as_slice()).unwrap();
+/// assert_eq!(decoded, amount);
+/// ```
+#[cfg(feature = "codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "codec")))]
+pub mod codec;
 /// Contains some constants and enums used in the Uniswap SDK Core
 pub mod constants;
 /// Contains entities related to the Uniswap SDK Core, such as currencies, tokens, and fractions.
@@ -33,6 +78,52 @@ pub mod entities;
 /// This module defines custom error types that are used throughout the SDK to
 /// handle various error conditions.
 pub mod error;
+/// Serde support for entities, fractions and chain metadata, enabled by the `serde` feature.
+///
+/// Human-readable formats such as JSON use the following encodings, with camelCase field names:
+///
+/// - `numerator` and `denominator` of [`Fraction`](entities::Fraction),
+///   [`Percent`](entities::Percent), [`Price`](entities::Price) and
+///   [`CurrencyAmount`](entities::CurrencyAmount) are decimal strings, so that 512-bit values
+///   survive parsers limited to `f64` numbers.
+/// - Addresses are EIP-55 checksummed strings. Lowercase input is accepted, mixed case input must
+///   carry a valid checksum.
+/// - Chain IDs, including [`ChainId`](chains::ChainId), are numbers.
+/// - A [`Token`](entities::Token) is `{ chainId, address, decimals, symbol, name, buyFeeBps,
+///   sellFeeBps }` where the fees default to zero, and an [`Ether`](entities::Ether) is `{
+///   chainId, decimals, symbol, name, wrapped }`.
+/// - A [`Currency`](entities::Currency) is `{ "native": Ether }` or `{ "token": Token }`.
+/// - A [`Price`](entities::Price) is `{ baseCurrency, quoteCurrency, numerator, denominator }`
+///   and a [`CurrencyAmount`](entities::CurrencyAmount) is `{ currency, numerator, denominator }`.
+///   Derived values such as the price scalar are recomputed when decoding.
+/// - [`TradeType`](constants::TradeType) is `"exactInput"` or `"exactOutput"`.
+///
+/// Compact formats such as bincode keep the same field order but encode integers as minimal
+/// big-endian two's complement bytes and addresses as their 20 raw bytes.
+///
+/// Decoding rejects zero denominators, unknown chain IDs, bad address checksums and currency
+/// amounts exceeding `MAX_UINT256`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+/// [`proptest`] strategies for entities and fractions, enabled by the `proptest` feature.
+///
+/// The strategies generate valid values only: tokens on supported chains with decimals up to 36
+/// and fees up to 100%, fractions with non-zero denominators, percentages between 0% and 100%,
+/// positive prices and non-negative currency amounts bounded by `MAX_UINT256`. Numerators and
+/// denominators stay below 2^128 so that sums and products of a few values do not overflow.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use uniswap_sdk_core::{prelude::*, strategies};
+///
+/// proptest!(|(a in strategies::fraction(), b in strategies::fraction())| {
+///     prop_assert_eq!(a.clone() + &b, b + &a);
+/// });
+/// ```
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod strategies;
 /// Contains utility functions and helpers used across the Uniswap SDK Core.
 pub mod utils;
 
@@ -41,7 +132,14 @@ pub mod utils;
 /// This module re-exports items that are commonly used together,
 /// making it easier to import them in other parts of your application.
 pub mod prelude {
-    pub use crate::{addresses::*, chains::*, constants::*, entities::*, error::Error, utils::*};
+    pub use crate::{
+        addresses::*,
+        chains::*,
+        constants::*,
+        entities::*,
+        error::{CurrencyId, Error},
+        utils::*,
+    };
 
     pub use alloc::{
         string::{String, ToString},
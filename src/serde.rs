@@ -0,0 +1,682 @@
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use core::fmt;
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, SeqAccess, Visitor},
+};
+
+/// Number of bytes in the two's complement representation of a [`BigInt`]
+const BIG_INT_BYTES: usize = 64;
+
+/// [`Address`] written with its EIP-55 checksum in human-readable formats
+struct Checksummed(Address);
+
+impl Serialize for Checksummed {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            // `Display` renders the checksummed address
+            serializer.collect_str(&self.0)
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Checksummed {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return Address::deserialize(deserializer).map(Self);
+        }
+        let s = String::deserialize(deserializer)?;
+        // mixed case carries a checksum, which must be valid
+        let mixed_case = s.bytes().any(|b| b.is_ascii_uppercase())
+            && s.bytes().skip(2).any(|b| b.is_ascii_lowercase());
+        let address = if mixed_case {
+            Address::parse_checksummed(&s, None).map_err(de::Error::custom)?
+        } else {
+            s.parse().map_err(de::Error::custom)?
+        };
+        Ok(Self(address))
+    }
+}
+
+/// [`BigInt`] written as a decimal string in human-readable formats and as minimal big-endian
+/// two's complement bytes otherwise
+#[derive(Clone, Copy)]
+struct BigIntRepr(BigInt);
+
+impl BigIntRepr {
+    /// Returns the shortest big-endian two's complement bytes of the value
+    #[inline]
+    fn to_bytes(self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .0
+            .to_bits()
+            .digits()
+            .iter()
+            .rev()
+            .flat_map(|digit| digit.to_be_bytes())
+            .collect();
+        // drop leading bytes that only repeat the sign bit
+        let redundant = bytes
+            .windows(2)
+            .take_while(|pair| {
+                (pair[0] == 0x00 && pair[1] & 0x80 == 0) || (pair[0] == 0xff && pair[1] & 0x80 != 0)
+            })
+            .count();
+        bytes.drain(..redundant);
+        bytes
+    }
+
+    /// Parses big-endian two's complement bytes produced by [`Self::to_bytes`]
+    #[inline]
+    fn from_bytes<E: de::Error>(bytes: &[u8]) -> Result<Self, E> {
+        if bytes.is_empty() || bytes.len() > BIG_INT_BYTES {
+            return Err(E::invalid_length(bytes.len(), &"1 to 64 bytes"));
+        }
+        let fill = if bytes[0] & 0x80 == 0 { 0x00 } else { 0xff };
+        let mut le_bytes = [fill; BIG_INT_BYTES];
+        for (dst, src) in le_bytes.iter_mut().zip(bytes.iter().rev()) {
+            *dst = *src;
+        }
+        BigUint::from_le_slice(&le_bytes)
+            .map(|bits| Self(BigInt::from_bits(bits)))
+            .ok_or_else(|| E::custom("invalid integer bytes"))
+    }
+}
+
+impl Serialize for BigIntRepr {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.0)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BigIntRepr {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = BigIntRepr;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("big-endian two's complement integer bytes")
+            }
+
+            #[inline]
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                BigIntRepr::from_bytes(v)
+            }
+
+            #[inline]
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                BigIntRepr::from_bytes(&bytes)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            BigInt::from_str_radix(&s, 10)
+                .map(Self)
+                .map_err(de::Error::custom)
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
+impl Serialize for ChainId {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(*self as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainId {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(u64::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ChainAddresses", rename_all = "camelCase")]
+struct ChainAddressesRepr {
+    v3_core_factory: Checksummed,
+    multicall: Checksummed,
+    quoter: Option<Checksummed>,
+    quoter_v2: Checksummed,
+    v3_migrator: Option<Checksummed>,
+    nonfungible_position_manager: Checksummed,
+    tick_lens: Option<Checksummed>,
+    swap_router02: Option<Checksummed>,
+    mixed_route_quoter_v1: Option<Checksummed>,
+    mixed_route_quoter_v2: Option<Checksummed>,
+    v4_pool_manager: Option<Checksummed>,
+    v4_position_manager: Option<Checksummed>,
+    v4_state_view: Option<Checksummed>,
+    v4_quoter: Option<Checksummed>,
+    permissioned_v4_position_manager: Option<Checksummed>,
+}
+
+impl Serialize for ChainAddresses {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let optional = |address: Option<Address>| address.map(Checksummed);
+        ChainAddressesRepr {
+            v3_core_factory: Checksummed(self.v3_core_factory),
+            multicall: Checksummed(self.multicall),
+            quoter: optional(self.quoter),
+            quoter_v2: Checksummed(self.quoter_v2),
+            v3_migrator: optional(self.v3_migrator),
+            nonfungible_position_manager: Checksummed(self.nonfungible_position_manager),
+            tick_lens: optional(self.tick_lens),
+            swap_router02: optional(self.swap_router02),
+            mixed_route_quoter_v1: optional(self.mixed_route_quoter_v1),
+            mixed_route_quoter_v2: optional(self.mixed_route_quoter_v2),
+            v4_pool_manager: optional(self.v4_pool_manager),
+            v4_position_manager: optional(self.v4_position_manager),
+            v4_state_view: optional(self.v4_state_view),
+            v4_quoter: optional(self.v4_quoter),
+            permissioned_v4_position_manager: optional(self.permissioned_v4_position_manager),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainAddresses {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ChainAddressesRepr::deserialize(deserializer)?;
+        let optional = |address: Option<Checksummed>| address.map(|address| address.0);
+        Ok(Self {
+            v3_core_factory: repr.v3_core_factory.0,
+            multicall: repr.multicall.0,
+            quoter: optional(repr.quoter),
+            quoter_v2: repr.quoter_v2.0,
+            v3_migrator: optional(repr.v3_migrator),
+            nonfungible_position_manager: repr.nonfungible_position_manager.0,
+            tick_lens: optional(repr.tick_lens),
+            swap_router02: optional(repr.swap_router02),
+            mixed_route_quoter_v1: optional(repr.mixed_route_quoter_v1),
+            mixed_route_quoter_v2: optional(repr.mixed_route_quoter_v2),
+            v4_pool_manager: optional(repr.v4_pool_manager),
+            v4_position_manager: optional(repr.v4_position_manager),
+            v4_state_view: optional(repr.v4_state_view),
+            v4_quoter: optional(repr.v4_quoter),
+            permissioned_v4_position_manager: optional(repr.permissioned_v4_position_manager),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Token", rename_all = "camelCase")]
+struct TokenRepr<'a> {
+    chain_id: u64,
+    address: Checksummed,
+    decimals: u8,
+    symbol: Option<Cow<'a, str>>,
+    name: Option<Cow<'a, str>>,
+    #[serde(default)]
+    buy_fee_bps: u64,
+    #[serde(default)]
+    sell_fee_bps: u64,
+}
+
+impl Serialize for Token {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TokenRepr {
+            chain_id: self.chain_id,
+            address: Checksummed(self.address),
+            decimals: self.decimals,
+            symbol: self.symbol.as_deref().map(Cow::Borrowed),
+            name: self.name.as_deref().map(Cow::Borrowed),
+            buy_fee_bps: self.buy_fee_bps,
+            sell_fee_bps: self.sell_fee_bps,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Token {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = TokenRepr::deserialize(deserializer)?;
+        Self::try_new(
+            repr.chain_id,
+            repr.address.0,
+            repr.decimals,
+            repr.symbol.map(Cow::into_owned),
+            repr.name.map(Cow::into_owned),
+            repr.buy_fee_bps,
+            repr.sell_fee_bps,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Ether", rename_all = "camelCase")]
+struct EtherRepr<'a> {
+    chain_id: u64,
+    decimals: u8,
+    symbol: Option<Cow<'a, str>>,
+    name: Option<Cow<'a, str>>,
+    wrapped: Option<Cow<'a, Token>>,
+}
+
+impl Serialize for Ether {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EtherRepr {
+            chain_id: self.chain_id,
+            decimals: self.decimals,
+            symbol: self.symbol.as_deref().map(Cow::Borrowed),
+            name: self.name.as_deref().map(Cow::Borrowed),
+            wrapped: self.meta.as_ref().map(Cow::Borrowed),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ether {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = EtherRepr::deserialize(deserializer)?;
+        if repr.chain_id == 0 {
+            return Err(de::Error::custom(Error::ZeroChainId));
+        }
+        if let Some(wrapped) = &repr.wrapped
+            && wrapped.chain_id != repr.chain_id
+        {
+            return Err(de::Error::custom(Error::ChainIdMismatch(
+                repr.chain_id,
+                wrapped.chain_id,
+            )));
+        }
+        Ok(Self {
+            chain_id: repr.chain_id,
+            decimals: repr.decimals,
+            symbol: repr.symbol.map(Cow::into_owned),
+            name: repr.name.map(Cow::into_owned),
+            meta: repr.wrapped.map(Cow::into_owned),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Currency", rename_all = "camelCase")]
+enum CurrencyRepr<'a> {
+    Native(Cow<'a, Ether>),
+    Token(Cow<'a, Token>),
+}
+
+impl Serialize for Currency {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::NativeCurrency(ether) => CurrencyRepr::Native(Cow::Borrowed(ether)),
+            Self::Token(token) => CurrencyRepr::Token(Cow::Borrowed(token)),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match CurrencyRepr::deserialize(deserializer)? {
+            CurrencyRepr::Native(ether) => Self::NativeCurrency(ether.into_owned()),
+            CurrencyRepr::Token(token) => Self::Token(token.into_owned()),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Fraction")]
+struct FractionRepr {
+    numerator: BigIntRepr,
+    denominator: BigIntRepr,
+}
+
+macro_rules! impl_plain_fraction_serde {
+    ($($fraction:ty),*) => {
+        $(
+            impl Serialize for $fraction {
+                #[inline]
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    FractionRepr {
+                        numerator: BigIntRepr(self.numerator),
+                        denominator: BigIntRepr(self.denominator),
+                    }
+                    .serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $fraction {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = FractionRepr::deserialize(deserializer)?;
+                    FractionBase::try_new(repr.numerator.0, repr.denominator.0, Default::default())
+                        .map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+impl_plain_fraction_serde!(Fraction, Percent);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Price", rename_all = "camelCase")]
+struct PriceRepr<'a, TBase: Clone, TQuote: Clone> {
+    base_currency: Cow<'a, TBase>,
+    quote_currency: Cow<'a, TQuote>,
+    numerator: BigIntRepr,
+    denominator: BigIntRepr,
+}
+
+impl<TBase, TQuote> Serialize for Price<TBase, TQuote>
+where
+    TBase: BaseCurrency + Serialize,
+    TQuote: BaseCurrency + Serialize,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PriceRepr {
+            base_currency: Cow::Borrowed(&self.meta.base_currency),
+            quote_currency: Cow::Borrowed(&self.meta.quote_currency),
+            numerator: BigIntRepr(self.numerator),
+            denominator: BigIntRepr(self.denominator),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, TBase, TQuote> Deserialize<'de> for Price<TBase, TQuote>
+where
+    TBase: BaseCurrency + Deserialize<'de>,
+    TQuote: BaseCurrency + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = PriceRepr::<TBase, TQuote>::deserialize(deserializer)?;
+        Self::try_new(
+            repr.base_currency.into_owned(),
+            repr.quote_currency.into_owned(),
+            repr.denominator.0,
+            repr.numerator.0,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "CurrencyAmount")]
+struct CurrencyAmountRepr<'a, T: Clone> {
+    currency: Cow<'a, T>,
+    numerator: BigIntRepr,
+    denominator: BigIntRepr,
+}
+
+impl<T: BaseCurrency + Serialize> Serialize for CurrencyAmount<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CurrencyAmountRepr {
+            currency: Cow::Borrowed(&self.meta.currency),
+            numerator: BigIntRepr(self.numerator),
+            denominator: BigIntRepr(self.denominator),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: BaseCurrency + Deserialize<'de>> Deserialize<'de> for CurrencyAmount<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CurrencyAmountRepr::<T>::deserialize(deserializer)?;
+        Self::from_fractional_amount(
+            repr.currency.into_owned(),
+            repr.numerator.0,
+            repr.denominator.0,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+    use alloy_primitives::address;
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+
+    fn usdc() -> Token {
+        token!(
+            1,
+            "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            6,
+            "USDC",
+            "USD Coin"
+        )
+    }
+
+    fn round_trip<T>(value: &T) -> serde_json::Value
+    where
+        T: Serialize + DeserializeOwned + PartialEq + fmt::Debug,
+    {
+        let json = serde_json::to_value(value).unwrap();
+        assert_eq!(&serde_json::from_value::<T>(json.clone()).unwrap(), value);
+        let bytes = bincode::serialize(value).unwrap();
+        assert_eq!(&bincode::deserialize::<T>(&bytes).unwrap(), value);
+        json
+    }
+
+    #[test]
+    fn test_big_int_bytes() {
+        for (value, bytes) in [
+            (BigInt::ZERO, &[0x00][..]),
+            (BigInt::from(127), &[0x7f]),
+            (BigInt::from(128), &[0x00, 0x80]),
+            (BigInt::from(-1), &[0xff]),
+            (BigInt::from(-128), &[0x80]),
+            (BigInt::from(-129), &[0xff, 0x7f]),
+        ] {
+            assert_eq!(BigIntRepr(value).to_bytes(), bytes);
+            let decoded = BigIntRepr::from_bytes::<de::value::Error>(bytes).unwrap();
+            assert_eq!(decoded.0, value);
+        }
+        for value in [BigInt::MAX, BigInt::MIN] {
+            let bytes = BigIntRepr(value).to_bytes();
+            assert_eq!(bytes.len(), BIG_INT_BYTES);
+            let decoded = BigIntRepr::from_bytes::<de::value::Error>(&bytes).unwrap();
+            assert_eq!(decoded.0, value);
+        }
+        assert!(BigIntRepr::from_bytes::<de::value::Error>(&[]).is_err());
+        assert!(BigIntRepr::from_bytes::<de::value::Error>(&[1; 65]).is_err());
+    }
+
+    #[test]
+    fn test_fraction() {
+        let fraction = Fraction::new(-BigInt::MAX, 3);
+        assert_eq!(
+            round_trip(&fraction),
+            json!({ "numerator": (-BigInt::MAX).to_string(), "denominator": "3" })
+        );
+        assert_eq!(
+            round_trip(&Percent::new(1, 200)),
+            json!({ "numerator": "1", "denominator": "200" })
+        );
+        // small values take a few bytes in the compact encoding
+        assert!(bincode::serialize(&Fraction::new(1, 200)).unwrap().len() < 20);
+    }
+
+    #[test]
+    fn test_fraction_rejects_invalid() {
+        let zero = json!({ "numerator": "1", "denominator": "0" });
+        assert!(serde_json::from_value::<Fraction>(zero).is_err());
+        let number = json!({ "numerator": 1, "denominator": "2" });
+        assert!(serde_json::from_value::<Fraction>(number).is_err());
+        let garbage = json!({ "numerator": "1.5", "denominator": "2" });
+        assert!(serde_json::from_value::<Percent>(garbage).is_err());
+        for (numerator, denominator) in [(BigInt::ONE, BigInt::MIN), (BigInt::MIN, -BigInt::ONE)] {
+            let overflow = json!({
+                "numerator": numerator.to_string(),
+                "denominator": denominator.to_string(),
+            });
+            assert!(serde_json::from_value::<Fraction>(overflow.clone()).is_err());
+            assert!(serde_json::from_value::<Percent>(overflow).is_err());
+        }
+    }
+
+    #[test]
+    fn test_token() {
+        let json = round_trip(&usdc());
+        assert_eq!(
+            json,
+            json!({
+                "chainId": 1,
+                "address": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                "decimals": 6,
+                "symbol": "USDC",
+                "name": "USD Coin",
+                "buyFeeBps": 0,
+                "sellFeeBps": 0,
+            })
+        );
+        let fot = token!(1, "0000000000000000000000000000000000000001", 18);
+        let fot = Token::new(1, fot.address, 18, None, None, 100, 200);
+        round_trip(&fot);
+    }
+
+    #[test]
+    fn test_token_address_parsing() {
+        let token = |address: &str| {
+            serde_json::from_value::<Token>(json!({
+                "chainId": 1,
+                "address": address,
+                "decimals": 6,
+                "symbol": null,
+                "name": null,
+            }))
+        };
+        let expected = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        assert_eq!(
+            token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+                .unwrap()
+                .address,
+            expected
+        );
+        assert_eq!(
+            token("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")
+                .unwrap()
+                .address,
+            expected
+        );
+        // bad checksum
+        assert!(token("0xa0B86991c6218b36c1d19D4a2e9Eb0cE3606eB48").is_err());
+        assert!(token("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_currency() {
+        let ether = Ether::on_chain(1);
+        let json = round_trip(&Currency::from(ether.clone()));
+        assert_eq!(json["native"]["chainId"], 1);
+        assert_eq!(json["native"]["wrapped"]["symbol"], "WETH");
+        assert_eq!(round_trip(&Currency::from(usdc()))["token"]["decimals"], 6);
+        round_trip(&ether);
+        round_trip(&Ether::on_chain(123_456));
+    }
+
+    #[test]
+    fn test_ether_chain_id_validation() {
+        let ether = |chain_id: u64, wrapped: Option<Token>| {
+            serde_json::from_value::<Ether>(json!({
+                "chainId": chain_id,
+                "decimals": 18,
+                "symbol": "ETH",
+                "name": "Ether",
+                "wrapped": wrapped,
+            }))
+        };
+        let weth = Ether::on_chain(1).wrapped().unwrap().clone();
+        assert_eq!(ether(1, Some(weth.clone())).unwrap(), Ether::on_chain(1));
+        assert!(ether(123_456, None).is_ok());
+        assert!(ether(0, None).is_err());
+        // wrapped token on another chain
+        assert!(ether(10, Some(weth)).is_err());
+    }
+
+    #[test]
+    fn test_price() {
+        let weth = Ether::on_chain(1).wrapped().unwrap().clone();
+        let price = Price::new(weth, usdc(), BigInt::from(10).pow(18), 4_000_000_000_i64);
+        let json = round_trip(&price);
+        assert_eq!(json["quoteCurrency"]["symbol"], "USDC");
+        assert_eq!(json["numerator"], "4000000000");
+        assert_eq!(json["denominator"], "1000000000000000000");
+        let decoded: Price<Token, Token> = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(decoded.meta.scalar, price.meta.scalar);
+
+        let mut invalid = json;
+        invalid["numerator"] = "1".into();
+        for denominator in [BigInt::ZERO, BigInt::MIN] {
+            invalid["denominator"] = denominator.to_string().into();
+            assert!(serde_json::from_value::<Price<Token, Token>>(invalid.clone()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_currency_amount() {
+        let amount = CurrencyAmount::from_fractional_amount(usdc(), 5, 2).unwrap();
+        let json = round_trip(&amount);
+        assert_eq!(json["numerator"], "5");
+        assert_eq!(json["denominator"], "2");
+        let decoded: CurrencyAmount<Token> = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(decoded.meta.decimal_scale, amount.meta.decimal_scale);
+
+        let mut too_large = json;
+        too_large["numerator"] = (MAX_UINT256 + BigInt::ONE).to_string().into();
+        too_large["denominator"] = "1".into();
+        assert!(serde_json::from_value::<CurrencyAmount<Token>>(too_large.clone()).is_err());
+
+        let mut invalid = too_large;
+        invalid["numerator"] = "1".into();
+        for denominator in [BigInt::ZERO, BigInt::MIN] {
+            invalid["denominator"] = denominator.to_string().into();
+            assert!(serde_json::from_value::<CurrencyAmount<Token>>(invalid.clone()).is_err());
+        }
+
+        let native = CurrencyAmount::from_raw_amount(Currency::from(Ether::on_chain(1)), 42);
+        round_trip(&native.unwrap());
+    }
+
+    #[test]
+    fn test_chains() {
+        assert_eq!(round_trip(&ChainId::ARBITRUM_ONE), json!(42161));
+        assert!(serde_json::from_value::<ChainId>(json!(0)).is_err());
+        let addresses = *CHAIN_TO_ADDRESSES_MAP.get(&1).unwrap();
+        let json = round_trip(&addresses);
+        assert_eq!(
+            json["v3CoreFactory"],
+            "0x1F98431c8aD98523631AE4a59f267346ea31F984"
+        );
+        assert_eq!(round_trip(&TradeType::ExactOutput), json!("exactOutput"));
+    }
+}
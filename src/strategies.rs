@@ -0,0 +1,277 @@
+use crate::prelude::*;
+use core::fmt::Debug;
+use proptest::{prelude::*, sample::select};
+
+/// Largest number of decimals generated for a [`Token`]
+pub const MAX_TOKEN_DECIMALS: u8 = 36;
+
+/// Largest fee in basis points generated for a [`Token`], i.e. 100%
+pub const MAX_FEE_BPS: u64 = BPS_DENOMINATOR;
+
+/// Number of bits bounding the numerators and denominators generated by [`fraction()`], [`price()`]
+/// and [`currency_amount()`], leaving room for products and sums of several values in [`BigInt`]
+pub const FRACTION_BITS: u32 = 128;
+
+/// Generates magnitudes below `2^max_bits` whose bit lengths are spread evenly, so that small
+/// values are as likely as values near the bound
+///
+/// # Panics
+///
+/// Panics if `max_bits` exceeds 511.
+#[inline]
+pub fn big_uint(max_bits: u32) -> impl Strategy<Value = BigUint> {
+    assert!(max_bits < BigInt::BITS, "max_bits must be less than 512");
+    (0..=max_bits, any::<[u8; 64]>()).prop_map(|(bits, bytes)| {
+        let value = BigUint::from_le_slice(&bytes).unwrap_or_default();
+        if bits == 0 {
+            BigUint::ZERO
+        } else {
+            value >> (BigUint::BITS - bits)
+        }
+    })
+}
+
+/// Generates signed integers with magnitudes below `2^max_bits`
+///
+/// # Panics
+///
+/// Panics if `max_bits` exceeds 511.
+#[inline]
+pub fn big_int(max_bits: u32) -> impl Strategy<Value = BigInt> {
+    (big_uint(max_bits), any::<bool>()).prop_map(|(magnitude, negative)| {
+        let value = BigInt::from_bits(magnitude);
+        if negative { -value } else { value }
+    })
+}
+
+/// Generates positive integers below `2^max_bits`
+#[inline]
+fn positive_big_int(max_bits: u32) -> impl Strategy<Value = BigInt> {
+    big_uint(max_bits).prop_map(|magnitude| BigInt::from_bits(magnitude).max(BigInt::ONE))
+}
+
+/// Generates arbitrary addresses
+#[inline]
+pub fn address() -> impl Strategy<Value = Address> {
+    any::<[u8; 20]>().prop_map(Address::from)
+}
+
+/// Generates chain IDs of [`SUPPORTED_CHAINS`]
+#[inline]
+pub fn chain_id() -> impl Strategy<Value = u64> {
+    select(SUPPORTED_CHAINS.as_slice()).prop_map(|chain| chain as u64)
+}
+
+/// Generates tokens on `chain_id` with decimals up to [`MAX_TOKEN_DECIMALS`], optional symbols
+/// and names, and fees up to [`MAX_FEE_BPS`]
+#[inline]
+pub fn token_on_chain(chain_id: u64) -> impl Strategy<Value = Token> {
+    (
+        address(),
+        0..=MAX_TOKEN_DECIMALS,
+        proptest::option::of("[A-Z]{1,6}"),
+        proptest::option::of("[A-Za-z][A-Za-z ]{0,15}"),
+        0..=MAX_FEE_BPS,
+        0..=MAX_FEE_BPS,
+    )
+        .prop_map(
+            move |(address, decimals, symbol, name, buy_fee_bps, sell_fee_bps)| {
+                Token::new(
+                    chain_id,
+                    address,
+                    decimals,
+                    symbol,
+                    name,
+                    buy_fee_bps,
+                    sell_fee_bps,
+                )
+            },
+        )
+}
+
+/// Generates tokens on any of the [`SUPPORTED_CHAINS`], see [`token_on_chain`]
+#[inline]
+pub fn token() -> impl Strategy<Value = Token> {
+    chain_id().prop_flat_map(token_on_chain)
+}
+
+/// Generates native currencies and tokens on `chain_id`
+#[inline]
+pub fn currency_on_chain(chain_id: u64) -> impl Strategy<Value = Currency> {
+    prop_oneof![
+        Just(Currency::NativeCurrency(Ether::on_chain(chain_id))),
+        token_on_chain(chain_id).prop_map(Currency::Token),
+    ]
+}
+
+/// Generates native currencies and tokens on any of the [`SUPPORTED_CHAINS`]
+#[inline]
+pub fn currency() -> impl Strategy<Value = Currency> {
+    chain_id().prop_flat_map(currency_on_chain)
+}
+
+/// Generates fractions with numerators and non-zero denominators of either sign and magnitudes
+/// below `2^`[`FRACTION_BITS`]
+#[inline]
+pub fn fraction() -> impl Strategy<Value = Fraction> {
+    (
+        big_int(FRACTION_BITS),
+        positive_big_int(FRACTION_BITS),
+        any::<bool>(),
+    )
+        .prop_map(|(numerator, denominator, negative)| {
+            Fraction::new(numerator, if negative { -denominator } else { denominator })
+        })
+}
+
+/// Generates percentages between 0% and 100% inclusive
+#[inline]
+pub fn percent() -> impl Strategy<Value = Percent> {
+    positive_big_int(FRACTION_BITS).prop_flat_map(|denominator| {
+        (big_uint(FRACTION_BITS), Just(denominator)).prop_map(|(numerator, denominator)| {
+            let numerator = BigInt::from_bits(numerator) % (denominator + BigInt::ONE);
+            Percent::new(numerator, denominator)
+        })
+    })
+}
+
+/// Generates positive prices between currencies generated by `base` and `quote`
+#[inline]
+pub fn price<TBase, TQuote>(
+    base: impl Strategy<Value = TBase>,
+    quote: impl Strategy<Value = TQuote>,
+) -> impl Strategy<Value = Price<TBase, TQuote>>
+where
+    TBase: BaseCurrency + Debug,
+    TQuote: BaseCurrency + Debug,
+{
+    (
+        base,
+        quote,
+        positive_big_int(FRACTION_BITS),
+        positive_big_int(FRACTION_BITS),
+    )
+        .prop_map(|(base, quote, denominator, numerator)| {
+            Price::new(base, quote, denominator, numerator)
+        })
+}
+
+/// Generates non-negative amounts of currencies generated by `currency`, whose raw amounts may
+/// be fractional and never exceed [`MAX_UINT256`]
+#[inline]
+pub fn currency_amount<T: BaseCurrency + Debug>(
+    currency: impl Strategy<Value = T>,
+) -> impl Strategy<Value = CurrencyAmount<T>> {
+    (
+        currency,
+        big_uint(256),
+        prop_oneof![Just(BigInt::ONE), positive_big_int(64)],
+    )
+        .prop_map(|(currency, numerator, denominator)| {
+            let numerator = BigInt::from_bits(numerator).min(MAX_UINT256);
+            CurrencyAmount::from_fractional_amount(currency, numerator, denominator)
+                .expect("amount is bounded by MAX_UINT256")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cmp::Ordering;
+
+    proptest! {
+        #[test]
+        fn tokens_are_valid(token in token()) {
+            prop_assert!(token.chain_id != 0);
+            prop_assert!(token.decimals <= MAX_TOKEN_DECIMALS);
+            prop_assert!(token.buy_fee_bps <= MAX_FEE_BPS && token.sell_fee_bps <= MAX_FEE_BPS);
+        }
+
+        #[test]
+        fn currencies_are_consistent(currency in currency()) {
+            prop_assert!(SUPPORTED_CHAINS.iter().any(|chain| *chain as u64 == currency.chain_id()));
+            prop_assert!(currency.is_native() != currency.is_token());
+        }
+
+        #[test]
+        fn addition_is_associative_and_commutative(
+            a in fraction(),
+            b in fraction(),
+            c in fraction(),
+        ) {
+            prop_assert_eq!((a.clone() + &b) + &c, a.clone() + &(b.clone() + &c));
+            prop_assert_eq!(a.clone() + &b, b + &a);
+        }
+
+        #[test]
+        fn multiplication_is_associative_and_distributive(
+            a in fraction(),
+            b in fraction(),
+            c in fraction(),
+        ) {
+            prop_assert_eq!((a.clone() * &b) * &c, a.clone() * &(b.clone() * &c));
+            prop_assert_eq!(
+                a.clone() * &(b.clone() + &c),
+                a.clone() * &b + &(a * &c)
+            );
+        }
+
+        #[test]
+        fn inverses_cancel(a in fraction()) {
+            prop_assert_eq!(a.clone() + &(-a.clone()), Fraction::ZERO);
+            prop_assert_eq!(a.clone() - &a, Fraction::ZERO);
+            if !a.is_zero() {
+                prop_assert_eq!(a.clone() * &a.invert(), Fraction::ONE);
+                prop_assert_eq!(a.clone() / &a, Fraction::ONE);
+            }
+        }
+
+        #[test]
+        fn ordering_is_total_and_translation_invariant(
+            a in fraction(),
+            b in fraction(),
+            c in fraction(),
+        ) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+            prop_assert_eq!((a.clone() + &c).cmp(&(b.clone() + &c)), a.cmp(&b));
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+            prop_assert_eq!((a.clone() - &b).cmp(&Fraction::ZERO), a.cmp(&b));
+        }
+
+        #[test]
+        fn percents_are_bounded(percent in percent()) {
+            prop_assert!(percent.as_fraction() >= Fraction::ZERO);
+            prop_assert!(percent.as_fraction() <= Fraction::ONE);
+        }
+
+        #[test]
+        fn prices_invert_and_quote(
+            price in price(token_on_chain(1), token_on_chain(1)),
+            amount in big_uint(128),
+        ) {
+            prop_assert_eq!(&price.invert().invert(), &price);
+            let amount = CurrencyAmount::from_raw_amount(
+                price.base_currency.clone(),
+                BigInt::from_bits(amount),
+            )
+            .unwrap();
+            let quoted = price.quote(&amount).unwrap();
+            prop_assert_eq!(price.invert().quote(&quoted).unwrap(), amount);
+        }
+
+        #[test]
+        fn currency_amounts_are_bounded_and_cancel(
+            a in currency_amount(Just(Ether::on_chain(1))),
+            b in currency_amount(Just(Ether::on_chain(1))),
+        ) {
+            prop_assert!(a.quotient() <= MAX_UINT256);
+            prop_assert!(!a.numerator.is_negative());
+            if let Ok(sum) = a.add(&b) {
+                prop_assert_eq!(sum.subtract(&b).unwrap(), a);
+            }
+        }
+    }
+}
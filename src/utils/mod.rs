@@ -1,13 +1,17 @@
 pub mod compute_price_impact;
 pub mod compute_zksync_create2_address;
+pub mod parse_units;
 pub mod sorted_insert;
 pub mod sqrt;
+pub mod stack_buffer;
 mod types;
 
 pub use compute_price_impact::compute_price_impact;
 pub use compute_zksync_create2_address::compute_zksync_create2_address;
+pub use parse_units::parse_units;
 pub use sorted_insert::sorted_insert;
 pub use sqrt::sqrt;
+pub use stack_buffer::StackBuffer;
 pub use types::*;
 
 #[cfg(feature = "validate_parse_address")]
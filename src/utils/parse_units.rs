@@ -0,0 +1,121 @@
+use crate::{entities::fractions::fraction::parse_decimal, prelude::*};
+
+/// Parses a human-readable decimal amount into raw units of a currency with `decimals` decimals,
+/// e.g. `"1.25"` with 6 decimals is `1_250_000`.
+///
+/// Accepts an optional sign followed by a decimal number with an optional exponent, e.g.
+/// `"1.25"`, `"-0.5"` or `"1.5e3"`. Digits beyond the decimals of the currency are rejected or
+/// rounded according to `mode`.
+///
+/// # Arguments
+///
+/// * `value`: the decimal amount in units of the currency
+/// * `decimals`: the decimals of the currency
+/// * `mode`: how to handle digits beyond `decimals`
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidNumber`] if the string is malformed, [`Error::ExcessPrecision`] if it
+/// has more fraction digits than `decimals` in [`PrecisionMode::Exact`] mode and
+/// [`Error::UintOverflow`] if the raw amount exceeds [`MAX_UINT256`]. Numbers whose digits,
+/// exponent or raw amount do not fit in [`BigInt`] at all return [`Error::Overflow`].
+#[inline]
+pub fn parse_units(value: &str, decimals: u8, mode: PrecisionMode) -> Result<BigInt, Error> {
+    let scale = BigInt::TEN
+        .checked_pow(decimals as u32)
+        .ok_or(Error::Overflow(BigInt::BITS))?;
+    let raw = parse_decimal(value.trim())?.checked_mul(&Fraction::new(scale, 1))?;
+    let raw = match mode {
+        _ if raw.denominator == BigInt::ONE => raw.numerator,
+        PrecisionMode::Exact => return Err(Error::ExcessPrecision { decimals }),
+        PrecisionMode::Round(rounding) => raw.rounded_quotient(rounding),
+    };
+    if raw > MAX_UINT256 {
+        return Err(Error::uint_overflow(Fraction::new(raw, 1)));
+    }
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_parse_units() {
+        let exact = PrecisionMode::Exact;
+        assert_eq!(
+            parse_units("1.25", 6, exact).unwrap(),
+            BigInt::from(1_250_000)
+        );
+        assert_eq!(parse_units(" 1 ", 0, exact).unwrap(), BigInt::ONE);
+        assert_eq!(parse_units(".5", 1, exact).unwrap(), BigInt::from(5));
+        assert_eq!(parse_units("-0.5", 2, exact).unwrap(), BigInt::from(-50));
+        assert_eq!(parse_units("1.500000", 1, exact).unwrap(), BigInt::from(15));
+        assert_eq!(
+            parse_units("1.5e3", 18, exact).unwrap(),
+            BigInt::from(15) * BigInt::TEN.pow(20)
+        );
+        assert_eq!(parse_units("25e-2", 2, exact).unwrap(), BigInt::from(25));
+        assert_eq!(parse_units("0", 36, exact).unwrap(), BigInt::ZERO);
+        // trailing zeros beyond the range of a BigInt do not overflow
+        let zeros = "0".repeat(200);
+        assert_eq!(
+            parse_units(&format!("1.25{zeros}"), 6, exact).unwrap(),
+            BigInt::from(1_250_000)
+        );
+        assert_eq!(
+            parse_units(&format!("{zeros}1.25e-4"), 6, exact).unwrap(),
+            BigInt::from(125)
+        );
+    }
+
+    #[test]
+    fn test_parse_units_excess_precision() {
+        assert_eq!(
+            parse_units("1.2345678", 6, PrecisionMode::Exact),
+            Err(Error::ExcessPrecision { decimals: 6 })
+        );
+        assert_eq!(
+            parse_units("1e-7", 6, PrecisionMode::Exact),
+            Err(Error::ExcessPrecision { decimals: 6 })
+        );
+        let round = |rounding| parse_units("1.2345675", 6, PrecisionMode::Round(rounding)).unwrap();
+        assert_eq!(round(Rounding::RoundDown), BigInt::from(1_234_567));
+        assert_eq!(round(Rounding::RoundUp), BigInt::from(1_234_568));
+        assert_eq!(round(Rounding::RoundHalfUp), BigInt::from(1_234_568));
+        assert_eq!(round(Rounding::RoundHalfEven), BigInt::from(1_234_568));
+        assert_eq!(round(Rounding::RoundHalfDown), BigInt::from(1_234_567));
+    }
+
+    #[test]
+    fn test_parse_units_errors() {
+        for value in ["", ".", "abc", "1.2.3", "1e", "--1", "1,5", "0x10"] {
+            assert_eq!(
+                parse_units(value, 18, PrecisionMode::Exact),
+                Err(Error::InvalidNumber(value.into())),
+                "{value}"
+            );
+        }
+        let max = MAX_UINT256.to_string();
+        assert_eq!(
+            parse_units(&max, 0, PrecisionMode::Exact).unwrap(),
+            MAX_UINT256
+        );
+        assert_eq!(
+            parse_units(&max, 1, PrecisionMode::Exact),
+            Err(Error::uint_overflow(Fraction::new(
+                MAX_UINT256 * BigInt::TEN,
+                1
+            )))
+        );
+        assert_eq!(
+            parse_units("1e100", 0, PrecisionMode::Exact),
+            Err(Error::uint_overflow(Fraction::new(BigInt::TEN.pow(100), 1)))
+        );
+        assert_eq!(
+            parse_units("1e200", 0, PrecisionMode::Exact),
+            Err(Error::Overflow(BigInt::BITS))
+        );
+    }
+}
@@ -1,26 +1,32 @@
+use crate::error::Error;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 
 /// Given an array of items sorted by `comparator`, insert an item into its sort index and constrain
 /// the size to `maxSize` by removing the last item
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidMaxSize`] if `max_size` is zero or `items` already exceeds it.
 #[inline]
 pub fn sorted_insert<T: Clone>(
     items: &mut Vec<T>,
     add: T,
     max_size: usize,
     comparator: fn(&T, &T) -> Ordering,
-) -> Option<T> {
-    assert!(max_size > 0, "max_size must be greater than 0");
-    assert!(
-        items.len() <= max_size,
-        "array length cannot exceed max_size"
-    );
+) -> Result<Option<T>, Error> {
+    if max_size == 0 || items.len() > max_size {
+        return Err(Error::InvalidMaxSize {
+            len: items.len(),
+            max_size,
+        });
+    }
 
     let removed_item = if items.len() == max_size {
         match items.last() {
             Some(last) if comparator(&add, last) != Ordering::Greater => items.pop(),
             // short circuit if full and the additional item does not come before the last item
-            _ => return Some(add),
+            _ => return Ok(Some(add)),
         }
     } else {
         None
@@ -31,7 +37,7 @@ pub fn sorted_insert<T: Clone>(
     };
 
     items.insert(pos, add);
-    removed_item
+    Ok(removed_item)
 }
 
 #[cfg(test)]
@@ -48,79 +54,90 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_max_size_zero() {
         let mut arr = Vec::new();
-        sorted_insert(&mut arr, 1, 0, cmp).unwrap();
+        assert_eq!(
+            sorted_insert(&mut arr, 1, 0, cmp),
+            Err(Error::InvalidMaxSize {
+                len: 0,
+                max_size: 0
+            })
+        );
     }
 
     #[test]
-    #[should_panic(expected = "array length cannot exceed max_size")]
     fn test_length_greater_than_max_size() {
         let mut arr = vec![1, 2];
-        sorted_insert(&mut arr, 1, 1, cmp);
+        assert_eq!(
+            sorted_insert(&mut arr, 1, 1, cmp),
+            Err(Error::InvalidMaxSize {
+                len: 2,
+                max_size: 1
+            })
+        );
+        assert_eq!(arr, vec![1, 2]);
     }
 
     #[test]
     fn test_add_if_empty() {
         let mut arr = Vec::new();
-        assert_eq!(sorted_insert(&mut arr, 3, 2, cmp), None);
+        assert_eq!(sorted_insert(&mut arr, 3, 2, cmp), Ok(None));
         assert_eq!(arr, vec![3]);
     }
 
     #[test]
     fn test_add_if_not_full() {
         let mut arr = vec![1, 5];
-        assert_eq!(sorted_insert(&mut arr, 3, 3, cmp), None);
+        assert_eq!(sorted_insert(&mut arr, 3, 3, cmp), Ok(None));
         assert_eq!(arr, vec![1, 3, 5]);
     }
 
     #[test]
     fn test_add_if_will_not_be_full_after() {
         let mut arr = vec![1];
-        assert_eq!(sorted_insert(&mut arr, 0, 3, cmp), None);
+        assert_eq!(sorted_insert(&mut arr, 0, 3, cmp), Ok(None));
         assert_eq!(arr, vec![0, 1]);
     }
 
     #[test]
     fn test_return_add_if_sorts_after_last() {
         let mut arr = vec![1, 2, 3];
-        assert_eq!(sorted_insert(&mut arr, 4, 3, cmp), Some(4));
+        assert_eq!(sorted_insert(&mut arr, 4, 3, cmp), Ok(Some(4)));
         assert_eq!(arr, vec![1, 2, 3]);
     }
 
     #[test]
     fn test_remove_from_end_if_full() {
         let mut arr = vec![1, 3, 4];
-        assert_eq!(sorted_insert(&mut arr, 2, 3, cmp), Some(4));
+        assert_eq!(sorted_insert(&mut arr, 2, 3, cmp), Ok(Some(4)));
         assert_eq!(arr, vec![1, 2, 3]);
     }
 
     #[test]
     fn test_uses_comparator() {
         let mut arr = vec![4, 2, 1];
-        assert_eq!(sorted_insert(&mut arr, 3, 3, reverse_cmp), Some(1));
+        assert_eq!(sorted_insert(&mut arr, 3, 3, reverse_cmp), Ok(Some(1)));
         assert_eq!(arr, vec![4, 3, 2]);
     }
 
     #[test]
     fn test_max_size_of_1_empty_add() {
         let mut arr = Vec::new();
-        assert_eq!(sorted_insert(&mut arr, 3, 1, cmp), None);
+        assert_eq!(sorted_insert(&mut arr, 3, 1, cmp), Ok(None));
         assert_eq!(arr, vec![3]);
     }
 
     #[test]
     fn test_max_size_of_1_full_add_greater() {
         let mut arr = vec![2];
-        assert_eq!(sorted_insert(&mut arr, 3, 1, cmp), Some(3));
+        assert_eq!(sorted_insert(&mut arr, 3, 1, cmp), Ok(Some(3)));
         assert_eq!(arr, vec![2]);
     }
 
     #[test]
     fn test_max_size_of_1_full_add_lesser() {
         let mut arr = vec![4];
-        assert_eq!(sorted_insert(&mut arr, 3, 1, cmp), Some(4));
+        assert_eq!(sorted_insert(&mut arr, 3, 1, cmp), Ok(Some(4)));
         assert_eq!(arr, vec![3]);
     }
 }
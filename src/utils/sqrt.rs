@@ -11,7 +11,7 @@ use num_integer::Roots;
 #[inline]
 pub fn sqrt(value: BigInt) -> Result<BigInt, Error> {
     if value < BigInt::ZERO {
-        Err(Error::Invalid("NEGATIVE"))
+        Err(Error::negative(Fraction::new(value, 1)))
     } else {
         Ok(value.sqrt())
     }
@@ -0,0 +1,133 @@
+use core::{fmt, ops::Deref, str};
+
+/// A fixed-capacity string buffer living on the stack.
+///
+/// Implements [`fmt::Write`] so fractions can be formatted into it without heap allocation, e.g.
+/// with [`FractionBase::write_significant`](crate::entities::FractionBase::write_significant).
+/// Writes that would exceed the capacity `N` fail with [`fmt::Error`] and leave the buffer
+/// unchanged.
+#[derive(Clone, Copy)]
+pub struct StackBuffer<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackBuffer<N> {
+    /// Creates a new empty buffer.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the written contents as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // only whole `&str`s are ever copied into the buffer
+        str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
+
+    /// Returns the number of bytes written.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the capacity of the buffer in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Clears the buffer so it can be reused.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for StackBuffer<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for StackBuffer<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        if end > N {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<const N: usize> Deref for StackBuffer<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Display for StackBuffer<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for StackBuffer<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn test_write() {
+        let mut buf = StackBuffer::<8>::new();
+        assert!(buf.is_empty());
+        let n = 12;
+        write!(buf, "{n}-ab").unwrap();
+        assert_eq!(buf.as_str(), "12-ab");
+        assert_eq!(buf.len(), 5);
+        assert_eq!(&*buf, "12-ab");
+    }
+
+    #[test]
+    fn test_overflow_leaves_buffer_unchanged() {
+        let mut buf = StackBuffer::<4>::new();
+        buf.write_str("abc").unwrap();
+        assert_eq!(buf.write_str("de"), Err(fmt::Error));
+        assert_eq!(buf.as_str(), "abc");
+        buf.write_str("d").unwrap();
+        assert_eq!(buf.as_str(), "abcd");
+        buf.clear();
+        assert_eq!(buf.as_str(), "");
+        assert_eq!(buf.capacity(), 4);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::error::Error;
+use alloc::string::String;
 use regex::Regex;
 
 /// Checks if the input string is a valid Ethereum address.
@@ -10,17 +12,14 @@ use regex::Regex;
 ///
 /// * If the input string satisfies the condition of starting with `0x` and being 42 characters long
 ///   with only hexadecimal characters after `0x`, returns `Ok(ethereum_address.to_string())`.
-/// * Otherwise, returns an error message in the form of `Err(format!("{} is not a valid Ethereum
-///   address.", ethereum_address))`.
+/// * Otherwise, returns [`Error::InvalidAddress`] carrying the input string.
 #[inline]
-pub fn check_valid_ethereum_address(ethereum_address: &str) -> Result<&str, String> {
+pub fn check_valid_ethereum_address(ethereum_address: &str) -> Result<&str, Error> {
     let valid_address_regex = Regex::new(r"^0x[0-9a-fA-F]{40}$").unwrap();
     if valid_address_regex.is_match(ethereum_address) {
         Ok(ethereum_address)
     } else {
-        Err(format!(
-            "{ethereum_address} is not a valid Ethereum address."
-        ))
+        Err(Error::InvalidAddress(ethereum_address.into()))
     }
 }
 
@@ -35,12 +34,12 @@ pub fn check_valid_ethereum_address(ethereum_address: &str) -> Result<&str, Stri
 ///
 /// * If the input string satisfies the condition of starting with `0x` and being 42 characters long
 ///   with only hexadecimal characters after `0x`, returns the checksummed address.
-/// * Otherwise, returns an error message in the form of `Err(format!("{} is not a valid Ethereum
-///   address.", ethereum_address))`.
+/// * Otherwise, returns [`Error::InvalidAddress`] carrying the input string.
 #[inline]
-pub fn validate_and_parse_address(ethereum_address: &str) -> Result<String, String> {
+pub fn validate_and_parse_address(ethereum_address: &str) -> Result<String, Error> {
     let checksummed_address = eth_checksum::checksum(ethereum_address);
-    check_valid_ethereum_address(&checksummed_address)?;
+    check_valid_ethereum_address(&checksummed_address)
+        .map_err(|_| Error::InvalidAddress(ethereum_address.into()))?;
     Ok(checksummed_address)
 }
 
@@ -57,7 +56,10 @@ mod tests {
     #[test]
     fn test_invalid_ethereum_address() {
         let invalid_address = "0xinvalidaddress";
-        assert!(check_valid_ethereum_address(invalid_address).is_err());
+        assert_eq!(
+            check_valid_ethereum_address(invalid_address),
+            Err(Error::InvalidAddress(invalid_address.into()))
+        );
     }
 
     #[test]
@@ -72,9 +74,7 @@ mod tests {
         let invalid_address = "0xInvAlIdAddrEsS";
         assert_eq!(
             validate_and_parse_address(invalid_address),
-            Err(format!(
-                "{invalid_address} is not a valid Ethereum address."
-            ))
+            Err(Error::InvalidAddress(invalid_address.into()))
         );
     }
 }